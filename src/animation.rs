@@ -0,0 +1,273 @@
+use std::io::{Read, Seek};
+
+use crate::curve::Curve;
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::property_value::PropertyValue;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationKind {
+    Sequence,
+    Montage,
+}
+
+/// An `FAnimNotifyEvent`.
+#[derive(Debug, Clone, Default)]
+pub struct AnimNotify {
+    /// Notify name, or the notify class name for class based notifies.
+    pub name: String,
+    /// Time in seconds from the start of the animation.
+    pub trigger_time: f64,
+    /// Length of notify states, 0 for instant notifies.
+    pub duration: f64,
+    pub track_index: i32,
+    /// Name of the notify track in the editor.
+    pub track_name: Option<String>,
+    /// Object path of the `AnimNotify` or `AnimNotifyState` instance.
+    pub notify: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AnimCurve {
+    pub name: String,
+    pub curve: Option<Curve>,
+}
+
+/// An `FCompositeSection` of a montage.
+#[derive(Debug, Clone, Default)]
+pub struct MontageSection {
+    pub name: String,
+    pub start_time: f64,
+    pub next_section: Option<String>,
+}
+
+/// An `FSlotAnimationTrack` of a montage.
+#[derive(Debug, Clone, Default)]
+pub struct MontageSlot {
+    pub slot_name: String,
+    /// Object paths of the animations played by the slot's segments.
+    pub animations: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AnimationInfo {
+    pub kind: AnimationKind,
+    pub skeleton: Option<String>,
+    pub frame_count: Option<u32>,
+    /// Play length in seconds.
+    pub play_length: f64,
+    /// Frames per second.
+    pub sample_rate: Option<f64>,
+    pub rate_scale: f64,
+    pub notifies: Vec<AnimNotify>,
+    pub curves: Vec<AnimCurve>,
+    pub sections: Vec<MontageSection>,
+    pub slots: Vec<MontageSlot>,
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads the metadata of an `AnimSequence` or `AnimMontage` export (0-based
+    /// index into the export table) from its properties.
+    pub fn read_animation(&mut self, export_index: usize) -> Result<AnimationInfo> {
+        let class_name = self.get_export_class_name(export_index)?;
+        let kind = match class_name.as_str() {
+            "AnimSequence" => AnimationKind::Sequence,
+            "AnimMontage" => AnimationKind::Montage,
+            _ => return Err(ParseError::UnexpectedExportClass(class_name)),
+        };
+
+        let properties = self.read_export_properties(export_index)?;
+        let number = |name: &str| properties.get(name).and_then(PropertyValue::as_f64);
+
+        let play_length = number("SequenceLength").unwrap_or_default();
+        let frame_count = number("NumberOfSampledFrames")
+            .or_else(|| number("NumFrames"))
+            .or_else(|| number("NumberOfSampledKeys").map(|keys| (keys - 1.0).max(0.0)))
+            .map(|frames| frames as u32);
+        let sample_rate = [
+            "TargetFrameRate",
+            "SamplingFrameRate",
+            "PlatformTargetFrameRate",
+        ]
+        .iter()
+        .filter_map(|name| properties.get(name))
+        .find_map(frame_rate)
+        .or_else(|| {
+            frame_count
+                .filter(|_| play_length > 0.0)
+                .map(|frames| frames as f64 / play_length)
+        });
+
+        let track_names: Vec<String> = properties
+            .get("AnimNotifyTracks")
+            .and_then(PropertyValue::as_array)
+            .unwrap_or_default()
+            .iter()
+            .map(|track| {
+                track
+                    .field("TrackName")
+                    .and_then(PropertyValue::as_str)
+                    .unwrap_or_default()
+                    .to_string()
+            })
+            .collect();
+
+        let mut notifies = Vec::new();
+        let notify_values = properties
+            .get("Notifies")
+            .and_then(PropertyValue::as_array)
+            .unwrap_or_default();
+        for event in notify_values {
+            let notify = ["Notify", "NotifyStateClass"]
+                .iter()
+                .filter_map(|name| event.field(name)?.as_object_index())
+                .find(|index| *index != 0);
+
+            let name = match event
+                .field("NotifyName")
+                .and_then(PropertyValue::as_str)
+                .filter(|name| *name != "None")
+            {
+                Some(name) => name.to_string(),
+                None => match notify {
+                    Some(index) => {
+                        let class_path = self.get_object_class_path(index)?;
+                        class_path
+                            .rsplit('.')
+                            .next()
+                            .unwrap_or_default()
+                            .to_string()
+                    }
+                    None => String::new(),
+                },
+            };
+
+            let track_index = event
+                .field("TrackIndex")
+                .and_then(PropertyValue::as_i64)
+                .unwrap_or_default() as i32;
+
+            notifies.push(AnimNotify {
+                name,
+                trigger_time: linked_time(event),
+                duration: event
+                    .field("Duration")
+                    .and_then(PropertyValue::as_f64)
+                    .unwrap_or_default(),
+                track_index,
+                track_name: track_names.get(track_index as usize).cloned(),
+                notify: match notify {
+                    Some(index) => Some(self.get_object_path(index)?),
+                    None => None,
+                },
+            });
+        }
+
+        let curves = properties
+            .get("RawCurveData")
+            .and_then(|data| data.field("FloatCurves"))
+            .and_then(PropertyValue::as_array)
+            .unwrap_or_default()
+            .iter()
+            .map(|curve| AnimCurve {
+                name: curve
+                    .field("CurveName")
+                    .or_else(|| {
+                        curve
+                            .field("Name")
+                            .and_then(|name| name.field("DisplayName"))
+                    })
+                    .and_then(PropertyValue::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                curve: curve.field("FloatCurve").and_then(Curve::from_value),
+            })
+            .collect();
+
+        let sections = properties
+            .get("CompositeSections")
+            .and_then(PropertyValue::as_array)
+            .unwrap_or_default()
+            .iter()
+            .map(|section| MontageSection {
+                name: section
+                    .field("SectionName")
+                    .and_then(PropertyValue::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                start_time: linked_time(section),
+                next_section: section
+                    .field("NextSectionName")
+                    .and_then(PropertyValue::as_str)
+                    .filter(|name| *name != "None")
+                    .map(str::to_string),
+            })
+            .collect();
+
+        let slots = properties
+            .get("SlotAnimTracks")
+            .and_then(PropertyValue::as_array)
+            .unwrap_or_default()
+            .iter()
+            .map(|slot| MontageSlot {
+                slot_name: slot
+                    .field("SlotName")
+                    .and_then(PropertyValue::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                animations: slot
+                    .field("AnimTrack")
+                    .and_then(|track| track.field("AnimSegments"))
+                    .and_then(PropertyValue::as_array)
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|segment| segment.field("AnimReference")?.as_object_path())
+                    .collect(),
+            })
+            .collect();
+
+        Ok(AnimationInfo {
+            kind,
+            skeleton: properties
+                .get("Skeleton")
+                .and_then(PropertyValue::as_object_path),
+            frame_count,
+            play_length,
+            sample_rate,
+            rate_scale: number("RateScale").unwrap_or(1.0),
+            notifies,
+            curves,
+            sections,
+            slots,
+        })
+    }
+}
+
+/// Converts an `FFrameRate` struct to frames per second.
+fn frame_rate(value: &PropertyValue) -> Option<f64> {
+    let numerator = value.field("Numerator")?.as_f64()?;
+    let denominator = value.field("Denominator")?.as_f64()?;
+    (denominator != 0.0).then(|| numerator / denominator)
+}
+
+/// Absolute time of an `FAnimLinkableElement`, such as a notify or montage
+/// section, from its link method and value.
+fn linked_time(element: &PropertyValue) -> f64 {
+    let number = |name: &str| {
+        element
+            .field(name)
+            .and_then(PropertyValue::as_f64)
+            .unwrap_or_default()
+    };
+    let method = element
+        .field("LinkMethod")
+        .and_then(PropertyValue::as_str)
+        .unwrap_or_default();
+    match method.rsplit("::").next().unwrap_or_default() {
+        "Relative" => number("SegmentBeginTime") + number("LinkValue"),
+        "Proportional" => {
+            number("SegmentBeginTime") + number("LinkValue") * number("SegmentLength")
+        }
+        _ => number("LinkValue"),
+    }
+}
@@ -1,13 +1,40 @@
 use std::collections::HashMap;
 
-#[derive(Debug, Default)]
+use crate::tag_value::TagValue;
+
+#[derive(Debug, Default, Clone)]
 pub struct AssetRegistryData {
     pub object_path: String,
     pub object_class_name: String,
     pub tags: HashMap<String, String>,
 }
 
-#[derive(Debug, Default)]
+impl AssetRegistryData {
+    pub fn tag_value(&self, key: &str) -> Option<TagValue> {
+        self.tags.get(key).map(|value| TagValue::parse(value))
+    }
+}
+
+/// Dependency section of the package asset registry data, written by
+/// `UE::AssetRegistry::WritePackageData` for uncooked packages.
+#[derive(Debug, Default, Clone)]
+pub struct AssetRegistryDependencyData {
+    /// One flag per import, set when the import is used in game.
+    pub import_used_in_game: Vec<bool>,
+    /// One flag per soft package reference, set when the reference is used in game.
+    pub soft_package_used_in_game: Vec<bool>,
+    /// Extra build dependencies as (package name, EExtraDependencyFlags).
+    pub extra_package_dependencies: Vec<(String, u32)>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AssetRegistryPackageData {
+    pub assets: Vec<AssetRegistryData>,
+    /// `None` for cooked packages, which use the format predating dependency data.
+    pub dependency_data: Option<AssetRegistryDependencyData>,
+}
+
+#[derive(Debug, Default, Clone)]
 pub struct AssetData {
     pub asset_class_name: String,
     pub object_path_without_package_name: String,
@@ -0,0 +1,803 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::errors::{ParseError, Result};
+use crate::reader::UassetReader;
+use crate::tag_value::TagValue;
+
+/// FAssetRegistryVersion::Type
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[allow(dead_code)]
+pub enum EAssetRegistryVersion {
+    PreVersioning = 0,
+    HardSoftDependencies,
+    AddAssetRegistryState,
+    ChangedAssetData,
+    RemovedMD5Hash,
+    AddedHardManage,
+    AddedCookedMD5Hash,
+    AddedDependencyFlags,
+    // Major tag format change replacing the compact asset registry
+    FixedTags,
+    WorkspaceDomain,
+    PackageImportedClasses,
+    PackageFileSummaryVersionChange,
+    ObjectResourceOptionalVersionChange,
+    AddedChunkHashes,
+    // Classes are serialized as path names, e.g. /Script/Engine.StaticMesh
+    ClassPaths,
+    // Asset bundles are serialized as FTopLevelAssetPath
+    RemoveAssetPathFNames,
+    AddedHeader,
+    AssetPackageDataHasExtension,
+    AssetPackageDataHasPackageLocation,
+    MarshaledTextAsUTF8String,
+    PackageSavedHash,
+}
+
+const ASSET_REGISTRY_VERSION_GUID: [u32; 4] = [0x717F9EE7, 0xE9B0493A, 0x88B39132, 0x1B388107];
+
+// FixedTagPrivate store magics
+const STORE_OLD_BEGIN_MAGIC: u32 = 0x12345679;
+const STORE_BEGIN_MAGIC: u32 = 0x12345680;
+const STORE_END_MAGIC: u32 = 0x87654321;
+
+// Number of flag bits stored per package and manage dependency
+const PACKAGE_FLAG_SET_WIDTH: usize = 3;
+const MANAGE_FLAG_SET_WIDTH: usize = 1;
+
+#[derive(Debug, Clone, Default)]
+pub struct AssetBundleEntry {
+    pub bundle_name: String,
+    pub bundle_assets: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RegistryAssetData {
+    pub package_path: String,
+    pub asset_class_path: String,
+    pub package_name: String,
+    pub asset_name: String,
+    pub tags: HashMap<String, String>,
+    pub bundles: Vec<AssetBundleEntry>,
+    pub chunk_ids: Vec<i32>,
+    pub package_flags: u32,
+}
+
+impl RegistryAssetData {
+    pub fn object_path(&self) -> String {
+        format!("{}.{}", self.package_name, self.asset_name)
+    }
+
+    pub fn tag_value(&self, key: &str) -> Option<TagValue> {
+        self.tags.get(key).map(|value| TagValue::parse(value))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetIdentifier {
+    pub package_name: Option<String>,
+    pub primary_asset_type: Option<String>,
+    pub object_name: Option<String>,
+    pub value_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DependsNode {
+    pub identifier: AssetIdentifier,
+    /// Package dependencies as (node index, EDependencyProperty bits).
+    pub package_dependencies: Vec<(usize, u8)>,
+    pub name_dependencies: Vec<usize>,
+    /// Manage dependencies as (node index, manage flag bits).
+    pub manage_dependencies: Vec<(usize, u8)>,
+    pub referencers: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AssetPackageData {
+    pub package_name: String,
+    pub disk_size: i64,
+    pub package_guid: Option<[u8; 16]>,
+    pub package_saved_hash: Option<[u8; 20]>,
+    pub cooked_hash: Option<[u8; 16]>,
+    pub chunk_hashes: Vec<([u8; 12], [u8; 20])>,
+    pub file_version_ue4: i32,
+    pub file_version_ue5: i32,
+    pub file_version_licensee_ue4: i32,
+    pub flags: u32,
+    pub custom_versions: Vec<([u8; 16], i32)>,
+    pub imported_classes: Vec<String>,
+    pub extension: Option<u8>,
+    pub package_location: Option<u8>,
+}
+
+/// Project-wide asset registry (FAssetRegistryState), as found in a cooked
+/// game's `AssetRegistry.bin`.
+#[derive(Debug, Default)]
+pub struct AssetRegistryState {
+    pub version: i32,
+    pub filter_editor_only_data: bool,
+    pub assets: Vec<RegistryAssetData>,
+    pub depends_nodes: Vec<DependsNode>,
+    pub packages: Vec<AssetPackageData>,
+}
+
+impl AssetRegistryState {
+    pub fn read<R: Read + Seek>(reader: R) -> Result<Self> {
+        StateReader::new(reader)?.read_state()
+    }
+
+    pub fn assets_of_class<'a>(
+        &'a self,
+        class_path: &'a str,
+    ) -> impl Iterator<Item = &'a RegistryAssetData> {
+        self.assets.iter().filter(move |asset| {
+            asset.asset_class_path == class_path
+                || asset.asset_class_path.rsplit('.').next() == Some(class_path)
+        })
+    }
+
+    pub fn assets_with_tag<'a>(
+        &'a self,
+        tag: &'a str,
+    ) -> impl Iterator<Item = &'a RegistryAssetData> {
+        self.assets
+            .iter()
+            .filter(move |asset| asset.tags.contains_key(tag))
+    }
+
+    pub fn find_package(&self, package_name: &str) -> Option<&AssetPackageData> {
+        self.packages
+            .iter()
+            .find(|package| package.package_name == package_name)
+    }
+
+    pub fn find_depends_node(&self, package_name: &str) -> Option<&DependsNode> {
+        self.depends_nodes
+            .iter()
+            .find(|node| node.identifier.package_name.as_deref() == Some(package_name))
+    }
+
+    /// Package names the given package depends on.
+    pub fn package_dependencies(&self, package_name: &str) -> Vec<&str> {
+        self.find_depends_node(package_name)
+            .map(|node| {
+                node.package_dependencies
+                    .iter()
+                    .filter_map(|(index, _)| self.depends_nodes.get(*index))
+                    .filter_map(|node| node.identifier.package_name.as_deref())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Package names referencing the given package.
+    pub fn package_referencers(&self, package_name: &str) -> Vec<&str> {
+        self.find_depends_node(package_name)
+            .map(|node| {
+                node.referencers
+                    .iter()
+                    .filter_map(|index| self.depends_nodes.get(*index))
+                    .filter_map(|node| node.identifier.package_name.as_deref())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// FixedTagPrivate::FValueId type, stored in the low 3 bits of a value id.
+#[derive(Debug, Clone, Copy)]
+enum ValueType {
+    AnsiString,
+    WideString,
+    NumberlessName,
+    Name,
+    NumberlessExportPath,
+    ExportPath,
+    LocalizedText,
+}
+
+#[derive(Debug, Default)]
+struct TagStore {
+    values: HashMap<(u8, u32), String>,
+    numberless_pairs: Vec<(u32, u32)>,
+    pairs: Vec<((u32, u32), u32)>,
+}
+
+struct StateReader<R: Read + Seek> {
+    reader: R,
+    file_size: u64,
+    version: i32,
+    names: Vec<String>,
+    store: TagStore,
+}
+
+impl<R: Read + Seek> StateReader<R> {
+    fn new(mut reader: R) -> Result<Self> {
+        let file_size = reader.seek(SeekFrom::End(0))?;
+        reader.seek(SeekFrom::Start(0))?;
+        Ok(Self {
+            reader,
+            file_size,
+            version: 0,
+            names: Vec::new(),
+            store: TagStore::default(),
+        })
+    }
+
+    fn at_least(&self, version: EAssetRegistryVersion) -> bool {
+        self.version >= version as i32
+    }
+
+    fn max_elements(&mut self, element_size: u64) -> Result<usize> {
+        let position = self.reader.stream_position()?;
+        Ok((self.file_size.saturating_sub(position) / element_size) as usize)
+    }
+
+    fn read_count(&mut self, element_size: u64) -> Result<usize> {
+        let count = self.reader.read_i32::<LittleEndian>()?;
+        if count < 0 || count as usize > self.max_elements(element_size)? {
+            return Err(ParseError::InvalidArraySize(count));
+        }
+        Ok(count as usize)
+    }
+
+    fn read_state(mut self) -> Result<AssetRegistryState> {
+        let mut state = AssetRegistryState::default();
+
+        let mut guid = [0u32; 4];
+        for part in guid.iter_mut() {
+            *part = self.reader.read_u32::<LittleEndian>()?;
+        }
+
+        if guid != ASSET_REGISTRY_VERSION_GUID {
+            return Err(ParseError::UnsupportedAssetRegistryVersion(
+                EAssetRegistryVersion::PreVersioning as i32,
+            ));
+        }
+
+        self.version = self.reader.read_i32::<LittleEndian>()?;
+        state.version = self.version;
+
+        if !self.at_least(EAssetRegistryVersion::FixedTags) {
+            return Err(ParseError::UnsupportedAssetRegistryVersion(self.version));
+        }
+
+        if self.at_least(EAssetRegistryVersion::AddedHeader) {
+            state.filter_editor_only_data = self.reader.read_u32::<LittleEndian>()? != 0;
+        }
+
+        self.names = self.read_name_batch()?;
+        self.store = self.read_tag_store()?;
+
+        let n_assets = self.read_count(36)?;
+        state.assets.reserve(n_assets);
+        for _ in 0..n_assets {
+            state.assets.push(self.read_asset_data()?);
+        }
+
+        state.depends_nodes = self.read_dependencies()?;
+
+        let n_packages = self.read_count(16)?;
+        state.packages.reserve(n_packages);
+        for _ in 0..n_packages {
+            let package_name = self.read_name()?;
+            state.packages.push(self.read_package_data(package_name)?);
+        }
+
+        Ok(state)
+    }
+
+    fn read_name_batch(&mut self) -> Result<Vec<String>> {
+        let n_names = self.read_count(10)?;
+        if n_names == 0 {
+            return Ok(Vec::new());
+        }
+
+        let n_string_bytes = self.reader.read_u32::<LittleEndian>()?;
+        self.reader.read_u64::<LittleEndian>()?; // HashVersion
+        self.reader.skip_bytes(n_names as i64 * 8)?; // Hashes
+
+        let mut headers = Vec::with_capacity(n_names);
+        for _ in 0..n_names {
+            let high = self.reader.read_u8()?;
+            let low = self.reader.read_u8()?;
+            let is_utf16 = high & 0x80 != 0;
+            let len = (((high & 0x7F) as usize) << 8) | low as usize;
+            headers.push((is_utf16, len));
+        }
+
+        let mut strings = vec![0u8; n_string_bytes as usize];
+        self.reader.read_exact(&mut strings)?;
+
+        let mut names = Vec::with_capacity(n_names);
+        let mut cursor = 0usize;
+        for (is_utf16, len) in headers {
+            // Wide names are aligned to 2 bytes in the string blob
+            if is_utf16 {
+                cursor = cursor.next_multiple_of(2);
+            }
+            let byte_len = if is_utf16 { len * 2 } else { len };
+            let bytes = strings
+                .get(cursor..cursor + byte_len)
+                .ok_or(ParseError::InvalidArraySize(len as i32))?;
+            cursor += byte_len;
+
+            if is_utf16 {
+                let chars: Vec<u16> = bytes
+                    .chunks_exact(2)
+                    .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                    .collect();
+                names.push(String::from_utf16(&chars).map_err(|_| ParseError::InvalidUtf16)?);
+            } else {
+                names.push(String::from_utf8_lossy(bytes).into_owned());
+            }
+        }
+
+        Ok(names)
+    }
+
+    fn name_at(&self, index: u32, number: u32) -> Result<String> {
+        let base = self
+            .names
+            .get(index as usize)
+            .ok_or(ParseError::InvalidNameIndex(index as i32))?;
+        if number > 0 {
+            Ok(format!("{}_{}", base, number - 1))
+        } else {
+            Ok(base.clone())
+        }
+    }
+
+    fn read_name(&mut self) -> Result<String> {
+        let index = self.reader.read_u32::<LittleEndian>()?;
+        let number = self.reader.read_u32::<LittleEndian>()?;
+        self.name_at(index, number)
+    }
+
+    fn read_numberless_name(&mut self) -> Result<String> {
+        let index = self.reader.read_u32::<LittleEndian>()?;
+        self.name_at(index, 0)
+    }
+
+    fn read_class_path(&mut self, numberless: bool) -> Result<String> {
+        let read = |this: &mut Self| {
+            if numberless {
+                this.read_numberless_name()
+            } else {
+                this.read_name()
+            }
+        };
+
+        if self.at_least(EAssetRegistryVersion::ClassPaths) {
+            let package_name = read(self)?;
+            let asset_name = read(self)?;
+            Ok(format!("{}.{}", package_name, asset_name))
+        } else {
+            read(self)
+        }
+    }
+
+    fn read_export_path(&mut self, numberless: bool) -> Result<String> {
+        let class_path = self.read_class_path(numberless)?;
+        let (object, package) = if numberless {
+            (self.read_numberless_name()?, self.read_numberless_name()?)
+        } else {
+            (self.read_name()?, self.read_name()?)
+        };
+        Ok(format!("{}'{}.{}'", class_path, package, object))
+    }
+
+    fn read_tag_store(&mut self) -> Result<TagStore> {
+        let magic = self.reader.read_u32::<LittleEndian>()?;
+        let text_first = match magic {
+            STORE_BEGIN_MAGIC => true,
+            STORE_OLD_BEGIN_MAGIC => false,
+            _ => return Err(ParseError::InvalidAssetRegistryStore),
+        };
+
+        let mut counts = [0u32; 11];
+        for count in counts.iter_mut() {
+            *count = self.reader.read_u32::<LittleEndian>()?;
+        }
+        let [
+            n_numberless_names,
+            n_names,
+            n_numberless_export_paths,
+            n_export_paths,
+            n_texts,
+            n_ansi_string_offsets,
+            n_wide_string_offsets,
+            n_ansi_string_bytes,
+            n_wide_string_chars,
+            n_numberless_pairs,
+            n_pairs,
+        ] = counts;
+
+        if counts.iter().any(|count| *count as u64 > self.file_size) {
+            return Err(ParseError::InvalidAssetRegistryStore);
+        }
+
+        let mut store = TagStore::default();
+
+        let mut texts = Vec::with_capacity(n_texts as usize);
+        if text_first {
+            self.reader.read_u32::<LittleEndian>()?; // Text data size in bytes
+            for _ in 0..n_texts {
+                texts.push(self.reader.read_fstring()?);
+            }
+        }
+
+        for index in 0..n_numberless_names {
+            let name = self.read_numberless_name()?;
+            store
+                .values
+                .insert((ValueType::NumberlessName as u8, index), name);
+        }
+        for index in 0..n_names {
+            let name = self.read_name()?;
+            store.values.insert((ValueType::Name as u8, index), name);
+        }
+        for index in 0..n_numberless_export_paths {
+            let path = self.read_export_path(true)?;
+            store
+                .values
+                .insert((ValueType::NumberlessExportPath as u8, index), path);
+        }
+        for index in 0..n_export_paths {
+            let path = self.read_export_path(false)?;
+            store
+                .values
+                .insert((ValueType::ExportPath as u8, index), path);
+        }
+
+        if !text_first {
+            for _ in 0..n_texts {
+                texts.push(self.reader.read_fstring()?);
+            }
+        }
+        for (index, text) in texts.into_iter().enumerate() {
+            store
+                .values
+                .insert((ValueType::LocalizedText as u8, index as u32), text);
+        }
+
+        let mut ansi_offsets = Vec::with_capacity(n_ansi_string_offsets as usize);
+        for _ in 0..n_ansi_string_offsets {
+            ansi_offsets.push(self.reader.read_u32::<LittleEndian>()? as usize);
+        }
+        let mut wide_offsets = Vec::with_capacity(n_wide_string_offsets as usize);
+        for _ in 0..n_wide_string_offsets {
+            wide_offsets.push(self.reader.read_u32::<LittleEndian>()? as usize);
+        }
+
+        let mut ansi_strings = vec![0u8; n_ansi_string_bytes as usize];
+        self.reader.read_exact(&mut ansi_strings)?;
+
+        let mut wide_strings = Vec::with_capacity(n_wide_string_chars as usize);
+        for _ in 0..n_wide_string_chars {
+            wide_strings.push(self.reader.read_u16::<LittleEndian>()?);
+        }
+
+        for (index, offset) in ansi_offsets.into_iter().enumerate() {
+            let bytes = ansi_strings.get(offset..).unwrap_or_default();
+            let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+            store.values.insert(
+                (ValueType::AnsiString as u8, index as u32),
+                String::from_utf8_lossy(&bytes[..end]).into_owned(),
+            );
+        }
+        for (index, offset) in wide_offsets.into_iter().enumerate() {
+            let chars = wide_strings.get(offset..).unwrap_or_default();
+            let end = chars.iter().position(|c| *c == 0).unwrap_or(chars.len());
+            store.values.insert(
+                (ValueType::WideString as u8, index as u32),
+                String::from_utf16_lossy(&chars[..end]),
+            );
+        }
+
+        store.numberless_pairs.reserve(n_numberless_pairs as usize);
+        for _ in 0..n_numberless_pairs {
+            let key = self.reader.read_u32::<LittleEndian>()?;
+            let value = self.reader.read_u32::<LittleEndian>()?;
+            store.numberless_pairs.push((key, value));
+        }
+
+        store.pairs.reserve(n_pairs as usize);
+        for _ in 0..n_pairs {
+            let key_index = self.reader.read_u32::<LittleEndian>()?;
+            let key_number = self.reader.read_u32::<LittleEndian>()?;
+            let value = self.reader.read_u32::<LittleEndian>()?;
+            store.pairs.push(((key_index, key_number), value));
+        }
+
+        if self.reader.read_u32::<LittleEndian>()? != STORE_END_MAGIC {
+            return Err(ParseError::InvalidAssetRegistryStore);
+        }
+
+        Ok(store)
+    }
+
+    fn store_value(&self, value_id: u32) -> String {
+        // FValueId: 3 type bits followed by the index
+        let value_type = (value_id & 0x7) as u8;
+        let index = value_id >> 3;
+        self.store
+            .values
+            .get(&(value_type, index))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn read_tag_map(&mut self) -> Result<HashMap<String, String>> {
+        // FPartialMapHandle: numberless flag, 16-bit pair count and 32-bit first pair
+        let handle = self.reader.read_u64::<LittleEndian>()?;
+        let has_numberless_keys = handle >> 63 != 0;
+        let n_pairs = ((handle >> 32) & 0xFFFF) as usize;
+        let pair_begin = (handle & 0xFFFF_FFFF) as usize;
+
+        let mut tags = HashMap::with_capacity(n_pairs);
+        for pair_index in pair_begin..pair_begin + n_pairs {
+            let (key, value) = if has_numberless_keys {
+                let (key, value) = *self
+                    .store
+                    .numberless_pairs
+                    .get(pair_index)
+                    .ok_or(ParseError::InvalidAssetRegistryStore)?;
+                (self.name_at(key, 0)?, value)
+            } else {
+                let ((key_index, key_number), value) = *self
+                    .store
+                    .pairs
+                    .get(pair_index)
+                    .ok_or(ParseError::InvalidAssetRegistryStore)?;
+                (self.name_at(key_index, key_number)?, value)
+            };
+            tags.insert(key, self.store_value(value));
+        }
+
+        Ok(tags)
+    }
+
+    fn read_bundles(&mut self) -> Result<Vec<AssetBundleEntry>> {
+        let n_bundles = self.read_count(12)?;
+        let mut bundles = Vec::with_capacity(n_bundles);
+
+        for _ in 0..n_bundles {
+            let bundle_name = self.read_name()?;
+            let n_assets = self.read_count(12)?;
+            let mut bundle_assets = Vec::with_capacity(n_assets);
+
+            for _ in 0..n_assets {
+                let path = if self.at_least(EAssetRegistryVersion::RemoveAssetPathFNames) {
+                    let package_name = self.read_name()?;
+                    let asset_name = self.read_name()?;
+                    format!("{}.{}", package_name, asset_name)
+                } else {
+                    let asset_path = self.read_name()?;
+                    let sub_path = self.reader.read_fstring()?;
+                    if sub_path.is_empty() {
+                        asset_path
+                    } else {
+                        format!("{}:{}", asset_path, sub_path)
+                    }
+                };
+                bundle_assets.push(path);
+            }
+
+            bundles.push(AssetBundleEntry {
+                bundle_name,
+                bundle_assets,
+            });
+        }
+
+        Ok(bundles)
+    }
+
+    fn read_asset_data(&mut self) -> Result<RegistryAssetData> {
+        if !self.at_least(EAssetRegistryVersion::RemoveAssetPathFNames) {
+            self.read_name()?; // ObjectPath
+        }
+
+        let package_path = self.read_name()?;
+        let asset_class_path = self.read_class_path(false)?;
+        let package_name = self.read_name()?;
+        let asset_name = self.read_name()?;
+        let tags = self.read_tag_map()?;
+        let bundles = self.read_bundles()?;
+
+        let n_chunks = self.read_count(4)?;
+        let mut chunk_ids = Vec::with_capacity(n_chunks);
+        for _ in 0..n_chunks {
+            chunk_ids.push(self.reader.read_i32::<LittleEndian>()?);
+        }
+
+        Ok(RegistryAssetData {
+            package_path,
+            asset_class_path,
+            package_name,
+            asset_name,
+            tags,
+            bundles,
+            chunk_ids,
+            package_flags: self.reader.read_u32::<LittleEndian>()?,
+        })
+    }
+
+    fn read_asset_identifier(&mut self) -> Result<AssetIdentifier> {
+        let field_bits = self.reader.read_u8()?;
+        let read_field = |this: &mut Self, bit: u8| -> Result<Option<String>> {
+            if field_bits & bit != 0 {
+                Ok(Some(this.read_name()?))
+            } else {
+                Ok(None)
+            }
+        };
+
+        Ok(AssetIdentifier {
+            package_name: read_field(self, 1 << 0)?,
+            primary_asset_type: read_field(self, 1 << 1)?,
+            object_name: read_field(self, 1 << 2)?,
+            value_name: read_field(self, 1 << 3)?,
+        })
+    }
+
+    /// Reads a dependency list of node indices, optionally followed by a packed
+    /// bit array holding `flag_width` flag bits per dependency.
+    fn read_dependency_list(&mut self, flag_width: usize) -> Result<Vec<(usize, u8)>> {
+        let n_dependencies = self.read_count(4)?;
+
+        let mut indices = Vec::with_capacity(n_dependencies);
+        for _ in 0..n_dependencies {
+            indices.push(self.reader.read_i32::<LittleEndian>()? as usize);
+        }
+
+        let mut flags = vec![0u8; n_dependencies];
+        if flag_width > 0 {
+            let n_words = (n_dependencies * flag_width).div_ceil(32);
+            let mut words = Vec::with_capacity(n_words);
+            for _ in 0..n_words {
+                words.push(self.reader.read_u32::<LittleEndian>()?);
+            }
+            for (index, flag) in flags.iter_mut().enumerate() {
+                for bit in 0..flag_width {
+                    let bit_index = index * flag_width + bit;
+                    if words[bit_index / 32] & (1 << (bit_index % 32)) != 0 {
+                        *flag |= 1 << bit;
+                    }
+                }
+            }
+        }
+
+        Ok(indices.into_iter().zip(flags).collect())
+    }
+
+    fn read_dependencies(&mut self) -> Result<Vec<DependsNode>> {
+        let section_size = self.reader.read_i64::<LittleEndian>()?;
+        let position = self.reader.stream_position()? as i64;
+        let section_end = (section_size >= 0)
+            .then(|| position.checked_add(section_size))
+            .flatten()
+            .filter(|end| *end as u64 <= self.file_size)
+            .ok_or(ParseError::InvalidFileOffset {
+                offset: section_size,
+                file_size: self.file_size,
+            })?;
+
+        let n_nodes = self.read_count(17)?;
+        let mut nodes = Vec::with_capacity(n_nodes);
+
+        for _ in 0..n_nodes {
+            let identifier = self.read_asset_identifier()?;
+            let package_dependencies = self.read_dependency_list(PACKAGE_FLAG_SET_WIDTH)?;
+            let name_dependencies = self.read_dependency_list(0)?;
+            let manage_dependencies = self.read_dependency_list(MANAGE_FLAG_SET_WIDTH)?;
+            let referencers = self.read_dependency_list(0)?;
+
+            nodes.push(DependsNode {
+                identifier,
+                package_dependencies,
+                name_dependencies: name_dependencies.into_iter().map(|(i, _)| i).collect(),
+                manage_dependencies,
+                referencers: referencers.into_iter().map(|(i, _)| i).collect(),
+            });
+        }
+
+        self.reader.seek(SeekFrom::Start(section_end as u64))?;
+        Ok(nodes)
+    }
+
+    fn read_package_data(&mut self, package_name: String) -> Result<AssetPackageData> {
+        let mut package = AssetPackageData {
+            package_name,
+            disk_size: self.reader.read_i64::<LittleEndian>()?,
+            ..Default::default()
+        };
+
+        if self.at_least(EAssetRegistryVersion::PackageSavedHash) {
+            let mut hash = [0u8; 20];
+            self.reader.read_exact(&mut hash)?;
+            package.package_saved_hash = Some(hash);
+        } else {
+            let mut guid = [0u8; 16];
+            self.reader.read_exact(&mut guid)?;
+            package.package_guid = Some(guid);
+        }
+
+        if self.at_least(EAssetRegistryVersion::AddedCookedMD5Hash)
+            && self.reader.read_u32::<LittleEndian>()? != 0
+        {
+            let mut hash = [0u8; 16];
+            self.reader.read_exact(&mut hash)?;
+            package.cooked_hash = Some(hash);
+        }
+
+        if self.at_least(EAssetRegistryVersion::AddedChunkHashes) {
+            let n_hashes = self.read_count(32)?;
+            for _ in 0..n_hashes {
+                let mut chunk_id = [0u8; 12];
+                let mut hash = [0u8; 20];
+                self.reader.read_exact(&mut chunk_id)?;
+                self.reader.read_exact(&mut hash)?;
+                package.chunk_hashes.push((chunk_id, hash));
+            }
+        }
+
+        if self.at_least(EAssetRegistryVersion::WorkspaceDomain) {
+            package.file_version_ue4 = self.reader.read_i32::<LittleEndian>()?;
+            if self.at_least(EAssetRegistryVersion::PackageFileSummaryVersionChange) {
+                package.file_version_ue5 = self.reader.read_i32::<LittleEndian>()?;
+            }
+            package.file_version_licensee_ue4 = self.reader.read_i32::<LittleEndian>()?;
+            package.flags = self.reader.read_u32::<LittleEndian>()?;
+
+            let n_versions = self.read_count(20)?;
+            for _ in 0..n_versions {
+                let mut key = [0u8; 16];
+                self.reader.read_exact(&mut key)?;
+                let version = self.reader.read_i32::<LittleEndian>()?;
+                package.custom_versions.push((key, version));
+            }
+        }
+
+        if self.at_least(EAssetRegistryVersion::PackageImportedClasses) {
+            let n_classes = self.read_count(8)?;
+            for _ in 0..n_classes {
+                let class_name = self.read_name()?;
+                package.imported_classes.push(class_name);
+            }
+        }
+
+        if self.at_least(EAssetRegistryVersion::AssetPackageDataHasExtension) {
+            package.extension = Some(self.reader.read_u8()?);
+        }
+
+        if self.at_least(EAssetRegistryVersion::AssetPackageDataHasPackageLocation) {
+            package.package_location = Some(self.reader.read_u8()?);
+        }
+
+        Ok(package)
+    }
+}
+
+pub fn print_asset_registry_state(state: &AssetRegistryState, show_tags: bool) {
+    println!("Version              : {}", state.version);
+    println!("FilterEditorOnlyData : {}", state.filter_editor_only_data);
+    println!("Assets               : {}", state.assets.len());
+    println!("DependsNodes         : {}", state.depends_nodes.len());
+    println!("Packages             : {}", state.packages.len());
+
+    for asset in &state.assets {
+        println!();
+        println!("ObjectPath: {}", asset.object_path());
+        println!("Class     : {}", asset.asset_class_path);
+
+        if show_tags {
+            for (k, v) in &asset.tags {
+                println!("Tag {}: {}", k, v);
+            }
+        }
+    }
+}
@@ -0,0 +1,526 @@
+//! Rich and simple float curves as used by `UCurveFloat`, `UCurveVector`,
+//! `UCurveLinearColor` and `UCurveTable` rows.
+
+use byteorder::ReadBytesExt;
+use std::io::{Read, Seek};
+
+use crate::errors::Result;
+use crate::parser::UassetParser;
+use crate::property_value::{Property, PropertyValue, find_property};
+use crate::reader::UassetReader;
+
+/// Value of `FRichCurve::DefaultValue` when the curve has no default.
+const NO_DEFAULT_VALUE: f32 = f32::MAX;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RichCurveInterpMode {
+    #[default]
+    Linear,
+    Constant,
+    Cubic,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RichCurveTangentMode {
+    #[default]
+    Auto,
+    User,
+    Break,
+    None,
+    SmartAuto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RichCurveTangentWeightMode {
+    #[default]
+    WeightedNone,
+    WeightedArrive,
+    WeightedLeave,
+    WeightedBoth,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RichCurveExtrapolation {
+    Cycle,
+    CycleWithOffset,
+    Oscillate,
+    Linear,
+    #[default]
+    Constant,
+    None,
+}
+
+impl RichCurveInterpMode {
+    const NAMES: [&'static str; 4] = ["RCIM_Linear", "RCIM_Constant", "RCIM_Cubic", "RCIM_None"];
+
+    pub fn from_index(index: u8) -> Self {
+        match index {
+            0 => Self::Linear,
+            1 => Self::Constant,
+            2 => Self::Cubic,
+            _ => Self::None,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        enumerator_index(&Self::NAMES, name).map(Self::from_index)
+    }
+
+    pub fn name(self) -> &'static str {
+        Self::NAMES[self as usize]
+    }
+}
+
+impl RichCurveTangentMode {
+    const NAMES: [&'static str; 5] = [
+        "RCTM_Auto",
+        "RCTM_User",
+        "RCTM_Break",
+        "RCTM_None",
+        "RCTM_SmartAuto",
+    ];
+
+    pub fn from_index(index: u8) -> Self {
+        match index {
+            0 => Self::Auto,
+            1 => Self::User,
+            2 => Self::Break,
+            4 => Self::SmartAuto,
+            _ => Self::None,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        enumerator_index(&Self::NAMES, name).map(Self::from_index)
+    }
+
+    pub fn name(self) -> &'static str {
+        Self::NAMES[self as usize]
+    }
+}
+
+impl RichCurveTangentWeightMode {
+    const NAMES: [&'static str; 4] = [
+        "RCTWM_WeightedNone",
+        "RCTWM_WeightedArrive",
+        "RCTWM_WeightedLeave",
+        "RCTWM_WeightedBoth",
+    ];
+
+    pub fn from_index(index: u8) -> Self {
+        match index {
+            1 => Self::WeightedArrive,
+            2 => Self::WeightedLeave,
+            3 => Self::WeightedBoth,
+            _ => Self::WeightedNone,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        enumerator_index(&Self::NAMES, name).map(Self::from_index)
+    }
+
+    pub fn name(self) -> &'static str {
+        Self::NAMES[self as usize]
+    }
+}
+
+impl RichCurveExtrapolation {
+    const NAMES: [&'static str; 6] = [
+        "RCCE_Cycle",
+        "RCCE_CycleWithOffset",
+        "RCCE_Oscillate",
+        "RCCE_Linear",
+        "RCCE_Constant",
+        "RCCE_None",
+    ];
+
+    pub fn from_index(index: u8) -> Self {
+        match index {
+            0 => Self::Cycle,
+            1 => Self::CycleWithOffset,
+            2 => Self::Oscillate,
+            3 => Self::Linear,
+            4 => Self::Constant,
+            _ => Self::None,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        enumerator_index(&Self::NAMES, name).map(Self::from_index)
+    }
+
+    pub fn name(self) -> &'static str {
+        Self::NAMES[self as usize]
+    }
+}
+
+/// Finds an enumerator by its short or `EEnum::` qualified name.
+fn enumerator_index(names: &[&str], name: &str) -> Option<u8> {
+    let name = name.rsplit("::").next().unwrap_or(name);
+    names.iter().position(|n| *n == name).map(|i| i as u8)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RichCurveKey {
+    pub interp_mode: RichCurveInterpMode,
+    pub tangent_mode: RichCurveTangentMode,
+    pub tangent_weight_mode: RichCurveTangentWeightMode,
+    pub time: f32,
+    pub value: f32,
+    pub arrive_tangent: f32,
+    pub arrive_tangent_weight: f32,
+    pub leave_tangent: f32,
+    pub leave_tangent_weight: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SimpleCurveKey {
+    pub time: f32,
+    pub value: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RichCurve {
+    pub keys: Vec<RichCurveKey>,
+    pub default_value: Option<f32>,
+    pub pre_infinity_extrap: RichCurveExtrapolation,
+    pub post_infinity_extrap: RichCurveExtrapolation,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SimpleCurve {
+    pub keys: Vec<SimpleCurveKey>,
+    /// Interpolation used between all keys of the curve.
+    pub interp_mode: RichCurveInterpMode,
+    pub default_value: Option<f32>,
+    pub pre_infinity_extrap: RichCurveExtrapolation,
+    pub post_infinity_extrap: RichCurveExtrapolation,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Curve {
+    Rich(RichCurve),
+    Simple(SimpleCurve),
+}
+
+impl RichCurve {
+    /// Builds a curve from the tagged properties of an `FRichCurve`.
+    pub fn from_properties(properties: &[Property]) -> Self {
+        let (default_value, pre_infinity_extrap, post_infinity_extrap) =
+            curve_base_properties(properties);
+
+        let keys = find_property(properties, "Keys")
+            .and_then(|keys| keys.as_array())
+            .unwrap_or_default()
+            .iter()
+            .map(|key| RichCurveKey {
+                interp_mode: enum_field(key, "InterpMode")
+                    .and_then(RichCurveInterpMode::from_name)
+                    .unwrap_or_default(),
+                tangent_mode: enum_field(key, "TangentMode")
+                    .and_then(RichCurveTangentMode::from_name)
+                    .unwrap_or_default(),
+                tangent_weight_mode: enum_field(key, "TangentWeightMode")
+                    .and_then(RichCurveTangentWeightMode::from_name)
+                    .unwrap_or_default(),
+                time: float_field(key, "Time"),
+                value: float_field(key, "Value"),
+                arrive_tangent: float_field(key, "ArriveTangent"),
+                arrive_tangent_weight: float_field(key, "ArriveTangentWeight"),
+                leave_tangent: float_field(key, "LeaveTangent"),
+                leave_tangent_weight: float_field(key, "LeaveTangentWeight"),
+            })
+            .collect();
+
+        Self {
+            keys,
+            default_value,
+            pre_infinity_extrap,
+            post_infinity_extrap,
+        }
+    }
+
+    /// Evaluates the curve at `time`. Cubic segments use the key tangents
+    /// without weights; times outside the keys are clamped, or extended along
+    /// the end tangent for linear extrapolation.
+    pub fn eval(&self, time: f32) -> f32 {
+        let (Some(first), Some(last)) = (self.keys.first(), self.keys.last()) else {
+            return self.default_value.unwrap_or(0.0);
+        };
+
+        if time <= first.time {
+            if self.pre_infinity_extrap == RichCurveExtrapolation::Linear {
+                return first.value - (first.time - time) * first.arrive_tangent;
+            }
+            return first.value;
+        }
+        if time >= last.time {
+            if self.post_infinity_extrap == RichCurveExtrapolation::Linear {
+                return last.value + (time - last.time) * last.leave_tangent;
+            }
+            return last.value;
+        }
+
+        let next = self.keys.partition_point(|key| key.time <= time);
+        let (key1, key2) = (&self.keys[next - 1], &self.keys[next]);
+        let diff = key2.time - key1.time;
+        if diff <= 0.0 {
+            return key2.value;
+        }
+        let alpha = (time - key1.time) / diff;
+
+        match key1.interp_mode {
+            RichCurveInterpMode::Constant | RichCurveInterpMode::None => key1.value,
+            RichCurveInterpMode::Linear => key1.value + (key2.value - key1.value) * alpha,
+            RichCurveInterpMode::Cubic => {
+                let p0 = key1.value;
+                let p1 = key1.value + key1.leave_tangent * diff / 3.0;
+                let p2 = key2.value - key2.arrive_tangent * diff / 3.0;
+                let p3 = key2.value;
+                bezier(p0, p1, p2, p3, alpha)
+            }
+        }
+    }
+}
+
+impl SimpleCurve {
+    /// Builds a curve from the tagged properties of an `FSimpleCurve`.
+    pub fn from_properties(properties: &[Property]) -> Self {
+        let (default_value, pre_infinity_extrap, post_infinity_extrap) =
+            curve_base_properties(properties);
+
+        let interp_mode = find_property(properties, "InterpMode")
+            .and_then(|mode| mode.as_str())
+            .and_then(RichCurveInterpMode::from_name)
+            .unwrap_or_default();
+
+        let keys = find_property(properties, "Keys")
+            .and_then(|keys| keys.as_array())
+            .unwrap_or_default()
+            .iter()
+            .map(|key| SimpleCurveKey {
+                time: float_field(key, "Time"),
+                value: float_field(key, "Value"),
+            })
+            .collect();
+
+        Self {
+            keys,
+            interp_mode,
+            default_value,
+            pre_infinity_extrap,
+            post_infinity_extrap,
+        }
+    }
+
+    /// Evaluates the curve at `time`, clamping outside the key range.
+    pub fn eval(&self, time: f32) -> f32 {
+        let (Some(first), Some(last)) = (self.keys.first(), self.keys.last()) else {
+            return self.default_value.unwrap_or(0.0);
+        };
+        if time <= first.time {
+            return first.value;
+        }
+        if time >= last.time {
+            return last.value;
+        }
+
+        let next = self.keys.partition_point(|key| key.time <= time);
+        let (key1, key2) = (&self.keys[next - 1], &self.keys[next]);
+        match self.interp_mode {
+            RichCurveInterpMode::Linear | RichCurveInterpMode::Cubic if key2.time > key1.time => {
+                let alpha = (time - key1.time) / (key2.time - key1.time);
+                key1.value + (key2.value - key1.value) * alpha
+            }
+            _ => key1.value,
+        }
+    }
+}
+
+impl Curve {
+    /// Key times and values, regardless of the curve kind.
+    pub fn points(&self) -> Vec<(f32, f32)> {
+        match self {
+            Curve::Rich(curve) => curve.keys.iter().map(|k| (k.time, k.value)).collect(),
+            Curve::Simple(curve) => curve.keys.iter().map(|k| (k.time, k.value)).collect(),
+        }
+    }
+
+    pub fn eval(&self, time: f32) -> f32 {
+        match self {
+            Curve::Rich(curve) => curve.eval(time),
+            Curve::Simple(curve) => curve.eval(time),
+        }
+    }
+
+    /// Builds a curve from a `RichCurve` or `SimpleCurve` struct value.
+    pub fn from_value(value: &PropertyValue) -> Option<Curve> {
+        match value {
+            PropertyValue::Struct {
+                struct_name,
+                properties,
+            } => match struct_name.as_str() {
+                "RichCurve" => Some(Curve::Rich(RichCurve::from_properties(properties))),
+                "SimpleCurve" => Some(Curve::Simple(SimpleCurve::from_properties(properties))),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+fn bezier(p0: f32, p1: f32, p2: f32, p3: f32, alpha: f32) -> f32 {
+    let p01 = p0 + (p1 - p0) * alpha;
+    let p12 = p1 + (p2 - p1) * alpha;
+    let p23 = p2 + (p3 - p2) * alpha;
+    let p012 = p01 + (p12 - p01) * alpha;
+    let p123 = p12 + (p23 - p12) * alpha;
+    p012 + (p123 - p012) * alpha
+}
+
+fn curve_base_properties(
+    properties: &[Property],
+) -> (Option<f32>, RichCurveExtrapolation, RichCurveExtrapolation) {
+    let default_value = find_property(properties, "DefaultValue")
+        .and_then(|value| value.as_f64())
+        .map(|value| value as f32)
+        .filter(|value| *value != NO_DEFAULT_VALUE);
+    let extrapolation = |name| {
+        find_property(properties, name)
+            .and_then(|value| value.as_str())
+            .and_then(RichCurveExtrapolation::from_name)
+            .unwrap_or_default()
+    };
+    (
+        default_value,
+        extrapolation("PreInfinityExtrap"),
+        extrapolation("PostInfinityExtrap"),
+    )
+}
+
+fn float_field(value: &PropertyValue, name: &str) -> f32 {
+    value
+        .field(name)
+        .and_then(|value| value.as_f64())
+        .unwrap_or_default() as f32
+}
+
+fn enum_field<'a>(value: &'a PropertyValue, name: &str) -> Option<&'a str> {
+    value.field(name).and_then(|value| value.as_str())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CurveTableMode {
+    #[default]
+    Empty,
+    SimpleCurves,
+    RichCurves,
+}
+
+#[derive(Debug, Clone)]
+pub struct CurveTableRow {
+    pub name: String,
+    pub curve: Curve,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CurveTable {
+    pub mode: CurveTableMode,
+    pub rows: Vec<CurveTableRow>,
+}
+
+impl CurveTable {
+    pub fn find(&self, row_name: &str) -> Option<&Curve> {
+        self.rows
+            .iter()
+            .find(|row| row.name == row_name)
+            .map(|row| &row.curve)
+    }
+
+    /// Renders the table in the editor's CSV export format: a header of the
+    /// key times of all rows, then one row of values per curve. Rows without
+    /// a key at a time get the curve evaluated there.
+    pub fn to_csv(&self) -> String {
+        let mut times: Vec<f32> = self
+            .rows
+            .iter()
+            .flat_map(|row| row.curve.points())
+            .map(|(time, _)| time)
+            .collect();
+        times.sort_by(f32::total_cmp);
+        times.dedup();
+
+        let mut csv = String::from("---");
+        for time in &times {
+            csv.push_str(&format!(",{}", time));
+        }
+        csv.push('\n');
+
+        for row in &self.rows {
+            let points = row.curve.points();
+            csv.push_str(&row.name);
+            for &time in &times {
+                let value = points
+                    .iter()
+                    .find(|(key_time, _)| *key_time == time)
+                    .map_or_else(|| row.curve.eval(time), |(_, value)| *value);
+                csv.push_str(&format!(",{}", value));
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads a `UCurveTable` export (0-based index into the export table).
+    pub fn read_curve_table(&mut self, export_index: usize) -> Result<CurveTable> {
+        self.seek_export_properties(export_index)?;
+
+        let n_rows = self.read_element_count()?;
+
+        let mode = match self.reader.read_u8()? {
+            1 => CurveTableMode::SimpleCurves,
+            2 => CurveTableMode::RichCurves,
+            _ => CurveTableMode::Empty,
+        };
+
+        let mut rows = Vec::with_capacity(n_rows);
+        for _ in 0..n_rows {
+            let name = self.reader.read_fname()?;
+            let name = self.get_name_string(&name)?;
+            let properties = self.read_tagged_properties()?;
+            let curve = match mode {
+                CurveTableMode::SimpleCurves => {
+                    Curve::Simple(SimpleCurve::from_properties(&properties))
+                }
+                _ => Curve::Rich(RichCurve::from_properties(&properties)),
+            };
+            rows.push(CurveTableRow { name, curve });
+        }
+
+        Ok(CurveTable { mode, rows })
+    }
+
+    /// Reads the curves of a curve asset export such as `UCurveFloat`,
+    /// `UCurveVector` or `UCurveLinearColor`. Curves stored in static arrays
+    /// are named with their index, e.g. `FloatCurves[2]`.
+    pub fn read_curves(&mut self, export_index: usize) -> Result<Vec<(String, Curve)>> {
+        let properties = self.read_export_properties(export_index)?;
+        Ok(properties
+            .properties
+            .iter()
+            .filter_map(|property| {
+                let curve = Curve::from_value(&property.value)?;
+                let name = if property.name == "FloatCurves" {
+                    format!("{}[{}]", property.name, property.array_index)
+                } else {
+                    property.name.clone()
+                };
+                Some((name, curve))
+            })
+            .collect())
+    }
+}
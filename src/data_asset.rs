@@ -0,0 +1,302 @@
+//! `UDataAsset` and `UPrimaryDataAsset` inspection: primary asset ids as the
+//! asset manager derives them, and the asset bundles of primary assets.
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::fmt;
+use std::io::{Read, Seek};
+
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::property_value::{Property, PropertyValue};
+use crate::tag_value::{StructText, TagValue};
+
+const RF_CLASS_DEFAULT_OBJECT: i32 = 0x0000_0010;
+
+/// Deepest class hierarchy followed when looking for the data asset base class.
+const MAX_CLASS_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataAssetKind {
+    /// Derives from `UPrimaryDataAsset` and is known to the asset manager.
+    Primary,
+    /// Derives from `UDataAsset` only.
+    Data,
+    /// The base class is not in this package, e.g. a native game class, but
+    /// the asset registry tags identify it as a primary asset.
+    Unknown,
+}
+
+/// An `FPrimaryAssetId`, written as `Type:Name`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrimaryAssetId {
+    pub primary_asset_type: String,
+    pub primary_asset_name: String,
+}
+
+impl fmt::Display for PrimaryAssetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.primary_asset_type, self.primary_asset_name)
+    }
+}
+
+/// An `FAssetBundleEntry`: assets loaded with the primary asset when the
+/// bundle is requested.
+#[derive(Debug, Clone, Default)]
+pub struct AssetBundle {
+    pub name: String,
+    /// Object paths of the bundle's assets.
+    pub assets: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DataAssetInfo {
+    pub path: String,
+    pub class_name: String,
+    pub kind: DataAssetKind,
+    /// `None` for data assets that aren't primary assets.
+    pub primary_asset_id: Option<PrimaryAssetId>,
+    pub properties: Vec<Property>,
+    pub bundles: Vec<AssetBundle>,
+    /// Distinct soft object paths referenced anywhere in the property tree.
+    pub soft_references: Vec<String>,
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Returns the indices of the top level data asset exports of the package:
+    /// instances of classes derived from `DataAsset`, and assets whose asset
+    /// registry tags carry a primary asset id.
+    pub fn find_data_assets(&mut self) -> Result<Vec<usize>> {
+        let n_exports = self.get_exports()?.len();
+        let mut indices = Vec::new();
+        for export_index in 0..n_exports {
+            let (outer_index, object_flags) = {
+                let export = &self.get_exports()?[export_index];
+                (export.outer_index, export.object_flags)
+            };
+            if outer_index != 0 || object_flags & RF_CLASS_DEFAULT_OBJECT != 0 {
+                continue;
+            }
+            if self.data_asset_kind(export_index)?.is_some() {
+                indices.push(export_index);
+            }
+        }
+        Ok(indices)
+    }
+
+    /// Reads a data asset export (0-based index into the export table): its
+    /// primary asset id, properties and asset bundles.
+    ///
+    /// The primary asset id is taken from the `PrimaryAssetType` and
+    /// `PrimaryAssetName` asset registry tags when present, which reflect any
+    /// `GetPrimaryAssetId` override. Otherwise `UPrimaryDataAsset` conventions
+    /// apply: the class name is the type and the object name the name.
+    pub fn read_data_asset(&mut self, export_index: usize) -> Result<DataAssetInfo> {
+        let package_index = export_index as i32 + 1;
+        let kind = self
+            .data_asset_kind(export_index)?
+            .unwrap_or(DataAssetKind::Data);
+        let class_name = self.get_export_class_name(export_index)?;
+
+        let registry_data = self.get_export_asset_registry_data(export_index)?;
+        let tag = |name: &str| {
+            registry_data
+                .as_ref()
+                .and_then(|data| data.tags.get(name))
+                .filter(|value| !value.is_empty() && *value != "None")
+                .cloned()
+        };
+        let primary_asset_id = match (tag("PrimaryAssetType"), tag("PrimaryAssetName")) {
+            (Some(primary_asset_type), Some(primary_asset_name)) => Some(PrimaryAssetId {
+                primary_asset_type,
+                primary_asset_name,
+            }),
+            _ if kind == DataAssetKind::Primary => Some(PrimaryAssetId {
+                primary_asset_type: class_name.clone(),
+                primary_asset_name: self.get_object_name(package_index)?,
+            }),
+            _ => None,
+        };
+
+        let properties = self.read_export_properties(export_index)?.properties;
+
+        let mut bundles = properties
+            .iter()
+            .find(|property| property.name == "AssetBundleData")
+            .map(|property| bundles_from_value(&property.value))
+            .unwrap_or_default();
+        if bundles.is_empty()
+            && let Some(TagValue::Struct(fields)) = registry_data
+                .as_ref()
+                .and_then(|data| data.tag_value("AssetBundleData"))
+        {
+            bundles = bundles_from_text(&StructText::Group(fields));
+        }
+
+        let mut soft_references = Vec::new();
+        for property in &properties {
+            collect_soft_references(&property.value, &mut soft_references);
+        }
+
+        Ok(DataAssetInfo {
+            path: self.get_object_path(package_index)?,
+            class_name,
+            kind,
+            primary_asset_id,
+            properties,
+            bundles,
+            soft_references,
+        })
+    }
+
+    /// Follows the class hierarchy of an export, through classes defined in
+    /// this package, up to `PrimaryDataAsset` or `DataAsset`. Returns
+    /// [`DataAssetKind::Unknown`] for other exports tagged with a primary
+    /// asset type.
+    fn data_asset_kind(&mut self, export_index: usize) -> Result<Option<DataAssetKind>> {
+        let mut class_index = self
+            .get_exports()?
+            .get(export_index)
+            .map(|export| export.class_index)
+            .ok_or(ParseError::InvalidPackageIndex(export_index as i32 + 1))?;
+        for _ in 0..MAX_CLASS_DEPTH {
+            if class_index == 0 {
+                break;
+            }
+            match self.get_object_name(class_index)?.as_str() {
+                "PrimaryDataAsset" => return Ok(Some(DataAssetKind::Primary)),
+                "DataAsset" => return Ok(Some(DataAssetKind::Data)),
+                _ => {}
+            }
+            if class_index < 0 {
+                break;
+            }
+            // The super struct is the first value after a class's properties
+            self.seek_export_properties(class_index as usize - 1)?;
+            class_index = self.reader.read_i32::<LittleEndian>()?;
+        }
+
+        let tagged = self
+            .get_export_asset_registry_data(export_index)?
+            .is_some_and(|data| data.tags.contains_key("PrimaryAssetType"));
+        Ok(tagged.then_some(DataAssetKind::Unknown))
+    }
+}
+
+/// Reads the bundles of an `FAssetBundleData` property value. UE 5.1
+/// replaced the `BundleAssets` soft object paths with `AssetPaths`
+/// top level asset paths.
+fn bundles_from_value(value: &PropertyValue) -> Vec<AssetBundle> {
+    value
+        .field("Bundles")
+        .and_then(PropertyValue::as_array)
+        .unwrap_or_default()
+        .iter()
+        .map(|entry| {
+            let mut assets: Vec<String> = entry
+                .field("BundleAssets")
+                .and_then(PropertyValue::as_array)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(PropertyValue::as_object_path)
+                .collect();
+            assets.extend(
+                entry
+                    .field("AssetPaths")
+                    .and_then(PropertyValue::as_array)
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(top_level_asset_path),
+            );
+            AssetBundle {
+                name: entry
+                    .field("BundleName")
+                    .and_then(PropertyValue::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                assets,
+            }
+        })
+        .collect()
+}
+
+/// Formats an `FTopLevelAssetPath` struct as `/Package/Path.AssetName`.
+fn top_level_asset_path(value: &PropertyValue) -> Option<String> {
+    let package = value.field("PackageName")?.as_str()?;
+    if package.is_empty() || package == "None" {
+        return None;
+    }
+    match value.field("AssetName").and_then(PropertyValue::as_str) {
+        Some(asset) if !asset.is_empty() && asset != "None" => Some(format!("{package}.{asset}")),
+        _ => Some(package.to_string()),
+    }
+}
+
+/// Reads the bundles of the `AssetBundleData` asset registry tag, e.g.
+/// `(Bundles=((BundleName="Game",AssetPaths=("/Game/A.A"))))`.
+fn bundles_from_text(text: &StructText) -> Vec<AssetBundle> {
+    text_elements(text.field("Bundles"))
+        .iter()
+        .map(|entry| {
+            let mut assets = Vec::new();
+            for name in ["BundleAssets", "AssetPaths"] {
+                for asset in text_elements(entry.field(name)) {
+                    let path = match asset {
+                        StructText::Scalar(path) => Some(path.as_str()),
+                        // Soft object paths of old versions may be written
+                        // as `(AssetPathName="...",SubPathString="")`
+                        group => group.field("AssetPathName").and_then(StructText::as_str),
+                    };
+                    assets.extend(
+                        path.filter(|path| !path.is_empty() && *path != "None")
+                            .map(str::to_string),
+                    );
+                }
+            }
+            AssetBundle {
+                name: entry
+                    .field("BundleName")
+                    .and_then(StructText::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                assets,
+            }
+        })
+        .collect()
+}
+
+/// Elements of an array written as struct text, e.g. `("A","B")`.
+fn text_elements(value: Option<&StructText>) -> Vec<&StructText> {
+    match value {
+        Some(StructText::Group(fields)) => fields.iter().map(|field| &field.value).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn collect_soft_references(value: &PropertyValue, references: &mut Vec<String>) {
+    match value {
+        PropertyValue::SoftObject(path) if !path.is_null() => {
+            let path = path.to_string();
+            if !references.contains(&path) {
+                references.push(path);
+            }
+        }
+        PropertyValue::Struct { properties, .. } => {
+            for property in properties {
+                collect_soft_references(&property.value, references);
+            }
+        }
+        PropertyValue::Array(values) | PropertyValue::Set(values) => {
+            for value in values {
+                collect_soft_references(value, references);
+            }
+        }
+        PropertyValue::Map(entries) => {
+            for (key, value) in entries {
+                collect_soft_references(key, references);
+                collect_soft_references(value, references);
+            }
+        }
+        PropertyValue::Optional(Some(value)) => collect_soft_references(value, references),
+        _ => {}
+    }
+}
@@ -0,0 +1,152 @@
+use crate::errors::{ParseError, Result};
+
+// EBulkDataFlags
+pub const BULKDATA_PAYLOAD_AT_END_OF_FILE: u32 = 0x0000_0001;
+pub const BULKDATA_SERIALIZE_COMPRESSED_ZLIB: u32 = 0x0000_0002;
+pub const BULKDATA_UNUSED: u32 = 0x0000_0020;
+pub const BULKDATA_FORCE_INLINE_PAYLOAD: u32 = 0x0000_0040;
+pub const BULKDATA_PAYLOAD_IN_SEPERATE_FILE: u32 = 0x0000_0100;
+pub const BULKDATA_OPTIONAL_PAYLOAD: u32 = 0x0000_0800;
+pub const BULKDATA_MEMORY_MAPPED_PAYLOAD: u32 = 0x0000_1000;
+pub const BULKDATA_SIZE_64BIT: u32 = 0x0000_2000;
+pub const BULKDATA_DUPLICATE_NON_OPTIONAL_PAYLOAD: u32 = 0x0000_4000;
+pub const BULKDATA_NO_OFFSET_FIX_UP: u32 = 0x0001_0000;
+
+/// Where the payload of a bulk data entry is stored relative to the package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkDataLocation {
+    /// Serialized inline with the owning export.
+    Inline,
+    /// Appended to the end of the package file.
+    EndOfPackage,
+    /// Stored in the `.ubulk` file next to the package.
+    BulkFile,
+    /// Stored in the memory mapped `.m.ubulk` file next to the package.
+    MemoryMappedBulkFile,
+    /// Stored in the optional `.uptnl` file next to the package.
+    OptionalFile,
+}
+
+impl BulkDataLocation {
+    /// File extension of the sibling file holding the payload, if any.
+    pub fn file_extension(&self) -> Option<&'static str> {
+        match self {
+            BulkDataLocation::Inline | BulkDataLocation::EndOfPackage => None,
+            BulkDataLocation::BulkFile => Some("ubulk"),
+            BulkDataLocation::MemoryMappedBulkFile => Some("m.ubulk"),
+            BulkDataLocation::OptionalFile => Some("uptnl"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DataResource {
+    pub flags: u32,
+    /// Index of the cooked package chunk holding the payload (UE 5.4+).
+    pub cooked_index: u8,
+    pub serial_offset: i64,
+    pub duplicate_serial_offset: i64,
+    pub serial_size: i64,
+    pub raw_size: i64,
+    pub outer_index: i32,
+    pub legacy_bulk_data_flags: u32,
+}
+
+impl DataResource {
+    pub fn location(&self) -> BulkDataLocation {
+        let flags = self.legacy_bulk_data_flags;
+        if flags & BULKDATA_OPTIONAL_PAYLOAD != 0 {
+            BulkDataLocation::OptionalFile
+        } else if flags & BULKDATA_PAYLOAD_IN_SEPERATE_FILE != 0 {
+            if flags & BULKDATA_MEMORY_MAPPED_PAYLOAD != 0 {
+                BulkDataLocation::MemoryMappedBulkFile
+            } else {
+                BulkDataLocation::BulkFile
+            }
+        } else if flags & BULKDATA_PAYLOAD_AT_END_OF_FILE != 0 {
+            BulkDataLocation::EndOfPackage
+        } else {
+            BulkDataLocation::Inline
+        }
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        self.legacy_bulk_data_flags & BULKDATA_SERIALIZE_COMPRESSED_ZLIB != 0
+    }
+
+    /// Unused bulk data has no payload anywhere.
+    pub fn is_unused(&self) -> bool {
+        self.legacy_bulk_data_flags & BULKDATA_UNUSED != 0
+    }
+
+    /// Optional payloads may also be duplicated into the `.ubulk` file.
+    pub fn has_duplicate_payload(&self) -> bool {
+        self.legacy_bulk_data_flags & BULKDATA_DUPLICATE_NON_OPTIONAL_PAYLOAD != 0
+    }
+}
+
+// FEditorBulkData::EFlags
+pub const EDITOR_BULKDATA_IS_VIRTUALIZED: u32 = 1 << 0;
+pub const EDITOR_BULKDATA_STORED_IN_PACKAGE_TRAILER: u32 = 1 << 8;
+
+/// Header of an editor bulk data payload (`FEditorBulkData`), used for
+/// texture and sound source data in UE5 editor packages.
+#[derive(Debug, Clone, Default)]
+pub struct EditorBulkData {
+    pub flags: u32,
+    pub bulk_data_id: [u8; 16],
+    /// FIoHash of the payload, also its identifier in the package trailer.
+    pub payload_id: [u8; 20],
+    pub payload_size: i64,
+    /// Offset of the payload in packages saved before the package trailer existed.
+    pub offset_in_file: Option<i64>,
+}
+
+impl EditorBulkData {
+    pub fn is_virtualized(&self) -> bool {
+        self.flags & EDITOR_BULKDATA_IS_VIRTUALIZED != 0
+    }
+
+    pub fn is_stored_in_package_trailer(&self) -> bool {
+        self.flags & EDITOR_BULKDATA_STORED_IN_PACKAGE_TRAILER != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.payload_size == 0
+    }
+}
+
+/// Size of the `FCompressedBuffer` header.
+pub const COMPRESSED_BUFFER_HEADER_SIZE: usize = 64;
+const COMPRESSED_BUFFER_MAGIC: u32 = 0xb775_6362;
+
+/// Returns the total size of an `FCompressedBuffer` from its header.
+pub fn compressed_buffer_size(header: &[u8]) -> Option<u64> {
+    if header.len() < COMPRESSED_BUFFER_HEADER_SIZE
+        || u32::from_be_bytes(header[0..4].try_into().ok()?) != COMPRESSED_BUFFER_MAGIC
+    {
+        return None;
+    }
+    let compressed_size = u64::from_be_bytes(header[24..32].try_into().ok()?);
+    (COMPRESSED_BUFFER_HEADER_SIZE as u64).checked_add(compressed_size)
+}
+
+/// Extracts the raw data of an `FCompressedBuffer` (big-endian header:
+/// magic, CRC, method, compressor, level, block size exponent, block count,
+/// raw size, compressed size, raw hash). Only uncompressed buffers are supported.
+pub fn decompress_buffer(buffer: &[u8]) -> Result<Vec<u8>> {
+    const METHOD_NONE: u8 = 0;
+
+    if compressed_buffer_size(buffer).is_none() {
+        return Err(ParseError::InvalidCompressedBuffer);
+    }
+
+    let method = buffer[8];
+    if method != METHOD_NONE {
+        return Err(ParseError::UnsupportedCompressionMethod(method));
+    }
+
+    let raw_size = u64::from_be_bytes(buffer[16..24].try_into().unwrap_or_default()) as usize;
+    let data = &buffer[COMPRESSED_BUFFER_HEADER_SIZE..];
+    Ok(data[..raw_size.min(data.len())].to_vec())
+}
@@ -0,0 +1,116 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{Read, Seek};
+
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::property_value::{Property, PropertyValue, find_property, json_string};
+use crate::reader::UassetReader;
+
+#[derive(Debug, Clone)]
+pub struct DataTableRow {
+    pub name: String,
+    pub properties: Vec<Property>,
+}
+
+impl DataTableRow {
+    pub fn get(&self, name: &str) -> Option<&PropertyValue> {
+        find_property(&self.properties, name)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DataTable {
+    /// Object path of the row struct, e.g. `/Script/MyGame.WeaponRow`.
+    pub row_struct: String,
+    pub rows: Vec<DataTableRow>,
+}
+
+impl DataTable {
+    /// Column names in order of first appearance. Rows only store the fields
+    /// that differ from the struct defaults, so a row may lack some columns.
+    pub fn columns(&self) -> Vec<String> {
+        let mut columns: Vec<String> = Vec::new();
+        for row in &self.rows {
+            for property in &row.properties {
+                let name = property.display_name();
+                if !columns.iter().any(|column| column == name) {
+                    columns.push(name.to_string());
+                }
+            }
+        }
+        columns
+    }
+
+    /// Renders the table in the editor's CSV export format.
+    pub fn to_csv(&self) -> String {
+        let columns = self.columns();
+
+        let mut csv = String::from("---");
+        for column in &columns {
+            csv.push(',');
+            csv.push_str(column);
+        }
+        csv.push('\n');
+
+        for row in &self.rows {
+            csv.push_str(&row.name);
+            for column in &columns {
+                let value = row.get(column).map(|v| v.export_text()).unwrap_or_default();
+                csv.push_str(",\"");
+                csv.push_str(&value.replace('"', "\"\""));
+                csv.push('"');
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Renders the table in the editor's JSON export format: an array of row
+    /// objects with the row name under `Name`.
+    pub fn to_json(&self) -> String {
+        let rows: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut fields = vec![format!("\t\t\"Name\": {}", json_string(&row.name))];
+                for property in &row.properties {
+                    fields.push(format!(
+                        "\t\t{}: {}",
+                        json_string(property.display_name()),
+                        property.value.to_json()
+                    ));
+                }
+                format!("\t{{\n{}\n\t}}", fields.join(",\n"))
+            })
+            .collect();
+        format!("[\n{}\n]\n", rows.join(",\n"))
+    }
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads a `UDataTable` export (0-based index into the export table): the
+    /// `RowStruct` reference from its properties and the serialized row map.
+    pub fn read_data_table(&mut self, export_index: usize) -> Result<DataTable> {
+        let properties = self.seek_export_properties(export_index)?;
+        let row_struct = properties
+            .get("RowStruct")
+            .and_then(|value| value.as_object_path())
+            .unwrap_or_default();
+
+        let n_rows = self.reader.read_i32::<LittleEndian>()?;
+        if n_rows < 0 {
+            return Err(ParseError::InvalidArraySize(n_rows));
+        }
+
+        let mut rows = Vec::new();
+        for _ in 0..n_rows {
+            let name = self.reader.read_fname()?;
+            rows.push(DataTableRow {
+                name: self.get_name_string(&name)?,
+                properties: self.read_tagged_properties()?,
+            });
+        }
+
+        Ok(DataTable { row_struct, rows })
+    }
+}
@@ -0,0 +1,197 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::property_value::PropertyValue;
+use crate::reader::UassetReader;
+
+/// `UEnum::ECppForm`: how enumerator names are qualified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnumCppForm {
+    /// `enum EFoo { Value }`, enumerators are stored unqualified.
+    #[default]
+    Regular,
+    /// `namespace EFoo { enum Type { Value } }`, stored as `EFoo::Value`.
+    Namespaced,
+    /// `enum class EFoo { Value }`, stored as `EFoo::Value`.
+    EnumClass,
+}
+
+impl EnumCppForm {
+    pub fn from_index(index: u8) -> Self {
+        match index {
+            1 => EnumCppForm::Namespaced,
+            2 => EnumCppForm::EnumClass,
+            _ => EnumCppForm::Regular,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct EnumEntry {
+    /// Enumerator name as stored, e.g. `EFoo::Value` or `NewEnumerator0`.
+    pub name: String,
+    pub value: i64,
+    /// Name shown in the editor, from `DisplayName` metadata or the
+    /// `DisplayNameMap` of user defined enums.
+    pub display_name: Option<String>,
+}
+
+impl EnumEntry {
+    /// Enumerator name without the enum prefix.
+    pub fn short_name(&self) -> &str {
+        self.name.rsplit("::").next().unwrap_or(&self.name)
+    }
+}
+
+/// A `UEnum` or `UUserDefinedEnum`.
+#[derive(Debug, Clone, Default)]
+pub struct EnumDefinition {
+    pub name: String,
+    pub path: String,
+    pub cpp_form: EnumCppForm,
+    pub entries: Vec<EnumEntry>,
+}
+
+impl EnumDefinition {
+    /// Looks up an enumerator by its stored, short or display name.
+    pub fn find(&self, name: &str) -> Option<&EnumEntry> {
+        let short_name = name.rsplit("::").next().unwrap_or(name);
+        self.entries.iter().find(|entry| {
+            entry.name == name
+                || entry.short_name() == short_name
+                || entry.display_name.as_deref() == Some(name)
+        })
+    }
+
+    pub fn find_value(&self, value: i64) -> Option<&EnumEntry> {
+        self.entries.iter().find(|entry| entry.value == value)
+    }
+
+    /// Resolves an enum property value, stored either as an enumerator name or
+    /// as its integer value, to the enumerator.
+    pub fn resolve(&self, value: &PropertyValue) -> Option<&EnumEntry> {
+        match value {
+            PropertyValue::Enum(name) | PropertyValue::Name(name) => self.find(name),
+            value => self.find_value(value.as_i64()?),
+        }
+    }
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads an `Enum` or `UserDefinedEnum` export (0-based index into the
+    /// export table) into its enumerators and their display names.
+    pub fn read_enum(&mut self, export_index: usize) -> Result<EnumDefinition> {
+        let class_name = self.get_export_class_name(export_index)?;
+        if class_name != "Enum" && class_name != "UserDefinedEnum" {
+            return Err(ParseError::UnexpectedExportClass(class_name));
+        }
+
+        let properties = self.seek_export_properties(export_index)?;
+
+        let n_entries = self.read_element_count()?;
+        let mut entries = Vec::with_capacity(n_entries);
+        for _ in 0..n_entries {
+            let name = self.reader.read_fname()?;
+            entries.push(EnumEntry {
+                name: self.get_name_string(&name)?,
+                value: self.reader.read_i64::<LittleEndian>()?,
+                display_name: None,
+            });
+        }
+        let cpp_form = EnumCppForm::from_index(self.reader.read_u8()?);
+
+        let package_index = export_index as i32 + 1;
+        let name = self.get_object_name(package_index)?;
+        let path = self.get_object_path(package_index)?;
+
+        // User defined enums keep display names in a property; native enums
+        // in `<Enumerator>.DisplayName` metadata of the enum object
+        if let Some(PropertyValue::Map(display_names)) = properties.get("DisplayNameMap") {
+            for (key, text) in display_names {
+                if let (Some(key), Some(text)) = (key.as_str(), text.as_str())
+                    && let Some(entry) = entries.iter_mut().find(|e| e.short_name() == key)
+                {
+                    entry.display_name = Some(text.to_string());
+                }
+            }
+        } else if let Some(metadata) = self.get_metadata()?.object_metadata.get(&path) {
+            for entry in &mut entries {
+                let key = format!("{}.DisplayName", entry.short_name());
+                entry.display_name = metadata.get(&key).cloned();
+            }
+        }
+
+        // The last enumerator is the implicit `_MAX` value
+        if entries
+            .last()
+            .is_some_and(|entry| entry.short_name().ends_with("_MAX"))
+        {
+            entries.pop();
+        }
+
+        Ok(EnumDefinition {
+            name,
+            path,
+            cpp_form,
+            entries,
+        })
+    }
+
+    /// Returns the in-package enum with the given object name.
+    pub fn find_enum(&mut self, name: &str) -> Result<Option<&EnumDefinition>> {
+        Ok(self.get_enums()?.iter().find(|e| e.name == name))
+    }
+
+    /// Resolves the object path of an enum referenced by name, e.g. from a
+    /// property type, looking at exports first and then imports.
+    pub fn resolve_enum_path(&mut self, name: &str) -> Result<Option<String>> {
+        if let Some(definition) = self.find_enum(name)? {
+            return Ok(Some(definition.path.clone()));
+        }
+
+        let n_imports = self.get_imports()?.len();
+        for import_index in 0..n_imports {
+            let (class_name, object_name) = {
+                let import = &self.get_imports()?[import_index];
+                (import.class_name.clone(), import.object_name.clone())
+            };
+            let class_name = self.get_name_string(&class_name)?;
+            if (class_name == "Enum" || class_name == "UserDefinedEnum")
+                && self.get_name_string(&object_name)? == name
+            {
+                return Ok(Some(self.get_object_path(-(import_index as i32) - 1)?));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Resolves an enum property value to its enumerator name using the enum
+    /// of the given name, when the enum is defined in this package.
+    pub fn resolve_enum_value(
+        &mut self,
+        enum_name: &str,
+        value: &PropertyValue,
+    ) -> Result<Option<String>> {
+        Ok(self
+            .find_enum(enum_name)?
+            .and_then(|definition| definition.resolve(value))
+            .map(|entry| entry.name.clone()))
+    }
+
+    pub(crate) fn read_enums(&mut self) -> Result<Vec<EnumDefinition>> {
+        let position = self.reader.stream_position()?;
+
+        let mut indices = self.find_exports_by_class("Enum")?;
+        indices.extend(self.find_exports_by_class("UserDefinedEnum")?);
+
+        let mut enums = Vec::with_capacity(indices.len());
+        for export_index in indices {
+            enums.push(self.read_enum(export_index)?);
+        }
+
+        self.reader.seek(SeekFrom::Start(position))?;
+        Ok(enums)
+    }
+}
@@ -35,6 +35,69 @@ pub enum ParseError {
 
     #[error("Invalid UTF-16 string")]
     InvalidUtf16,
+
+    #[error("Invalid localization metadata type: {0}")]
+    InvalidLocMetadataType(i32),
+
+    #[error("Invalid name index: {0}")]
+    InvalidNameIndex(i32),
+
+    #[error("Invalid package index: {0}")]
+    InvalidPackageIndex(i32),
+
+    #[error("Unexpected export class: {0}")]
+    UnexpectedExportClass(String),
+
+    #[error("Unsupported property type: {0}")]
+    UnsupportedPropertyType(String),
+
+    #[error("Unsupported text history type: {0}")]
+    UnsupportedTextHistory(i8),
+
+    #[error("Unversioned property serialization is not supported")]
+    UnversionedPropertiesNotSupported,
+
+    #[error("Unsupported data resource table version: {0}")]
+    UnsupportedDataResourceVersion(u32),
+
+    #[error("Bulk data payload has no package path to resolve {0}")]
+    MissingBulkDataFile(String),
+
+    #[error("Invalid compressed buffer header")]
+    InvalidCompressedBuffer,
+
+    #[error("Unsupported payload compression method: {0}")]
+    UnsupportedCompressionMethod(u8),
+
+    #[error("Compressed bulk data is not supported")]
+    CompressedBulkDataNotSupported,
+
+    #[error("Invalid bulk data size: {0}")]
+    InvalidBulkDataSize(i64),
+
+    #[error("Unsupported pixel format: {0}")]
+    UnsupportedPixelFormat(String),
+
+    #[error("Invalid texture size: {0}x{1}")]
+    InvalidTextureSize(i32, i32),
+
+    #[error("Unsupported Kismet bytecode token: 0x{0:02X}")]
+    UnsupportedKismetToken(u8),
+
+    #[error("Kismet expressions nested deeper than {0} levels")]
+    KismetExpressionTooDeep(usize),
+
+    #[error("Unsupported asset registry version: {0}")]
+    UnsupportedAssetRegistryVersion(i32),
+
+    #[error("Invalid asset registry tag store")]
+    InvalidAssetRegistryStore,
+
+    #[error("Invalid package trailer tag")]
+    InvalidPackageTrailerTag,
+
+    #[error("Payload is not stored locally: {0:?}")]
+    PayloadNotLocal(crate::payload_toc::PayloadAccessMode),
 }
 
 pub type Result<T> = std::result::Result<T, ParseError>;
@@ -0,0 +1,192 @@
+//! World Partition "One File Per Actor" support: actors and objects of a level
+//! saved in their own packages under `__ExternalActors__` and
+//! `__ExternalObjects__` next to the map.
+
+use std::fs::{self, File};
+use std::io::{BufReader, Read, Seek};
+use std::path::{Path, PathBuf};
+
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::property_value::PropertyValue;
+use crate::world::LevelActor;
+
+const EXTERNAL_ACTORS_FOLDER: &str = "__ExternalActors__";
+const EXTERNAL_OBJECTS_FOLDER: &str = "__ExternalObjects__";
+
+/// An actor stored in an external package.
+#[derive(Debug, Clone)]
+pub struct ExternalActor {
+    pub package_path: PathBuf,
+    /// Object path of the level the actor belongs to, e.g.
+    /// `/Game/Maps/Main.Main:PersistentLevel`.
+    pub level: String,
+    pub actor: LevelActor,
+    /// Data layer assets (or data layer names in UE 5.0) the actor is in.
+    pub data_layers: Vec<String>,
+    /// World Partition runtime grid, `None` for the default grid.
+    pub runtime_grid: Option<String>,
+    pub is_spatially_loaded: bool,
+    /// `EActorGridPlacement` enumerator of UE 5.0 packages.
+    pub grid_placement: Option<String>,
+}
+
+/// An object other than an actor stored in an external package, such as a
+/// data layer instance.
+#[derive(Debug, Clone)]
+pub struct ExternalObject {
+    pub package_path: PathBuf,
+    pub class_name: String,
+    /// Object path within the owning level or world.
+    pub path: String,
+}
+
+#[derive(Debug, Default)]
+pub struct ExternalPackages {
+    pub actors: Vec<ExternalActor>,
+    pub objects: Vec<ExternalObject>,
+    /// Packages that could not be parsed, with the error.
+    pub errors: Vec<(PathBuf, ParseError)>,
+}
+
+impl ExternalPackages {
+    /// Actors belonging to the given level path.
+    pub fn actors_in_level<'a>(
+        &'a self,
+        level: &'a str,
+    ) -> impl Iterator<Item = &'a ExternalActor> {
+        self.actors.iter().filter(move |actor| actor.level == level)
+    }
+}
+
+/// Returns the folders holding the external actor and external object
+/// packages of a map, e.g. `<Content>/__ExternalActors__/Maps/Main` for
+/// `<Content>/Maps/Main.umap`.
+pub fn external_package_folders(map_path: &Path, content_root: &Path) -> Option<[PathBuf; 2]> {
+    let relative = map_path.strip_prefix(content_root).ok()?.with_extension("");
+    Some([
+        content_root.join(EXTERNAL_ACTORS_FOLDER).join(&relative),
+        content_root.join(EXTERNAL_OBJECTS_FOLDER).join(&relative),
+    ])
+}
+
+/// Discovers and parses the external actor and object packages of a map
+/// given the content folder it lives in. Packages that fail to parse are
+/// reported in [`ExternalPackages::errors`] rather than aborting the scan.
+pub fn read_external_packages(map_path: &Path, content_root: &Path) -> Result<ExternalPackages> {
+    let mut packages = ExternalPackages::default();
+    let Some(folders) = external_package_folders(map_path, content_root) else {
+        return Ok(packages);
+    };
+
+    for folder in folders {
+        let mut files = Vec::new();
+        collect_packages(&folder, &mut files)?;
+        files.sort();
+
+        for package_path in files {
+            let result = File::open(&package_path)
+                .map_err(ParseError::from)
+                .and_then(|file| UassetParser::new(BufReader::new(file), true))
+                .and_then(|mut parser| parser.read_external_package(&package_path, &mut packages));
+            if let Err(error) = result {
+                packages.errors.push((package_path, error));
+            }
+        }
+    }
+
+    Ok(packages)
+}
+
+/// Collects the `.uasset` and `.umap` files under a folder.
+pub(crate) fn collect_packages(folder: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    if !folder.is_dir() {
+        return Ok(());
+    }
+    for entry in fs::read_dir(folder)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_packages(&path, files)?;
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext == "uasset" || ext == "umap")
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads the externally saved objects of this package, whose outers are
+    /// imports of the owning level or world. Exports outered to a
+    /// `PersistentLevel` import are reported as actors.
+    fn read_external_package(
+        &mut self,
+        package_path: &Path,
+        packages: &mut ExternalPackages,
+    ) -> Result<()> {
+        let n_exports = self.get_exports()?.len();
+        for export_index in 0..n_exports {
+            let outer_index = self.get_exports()?[export_index].outer_index;
+            if outer_index >= 0 {
+                continue;
+            }
+
+            let outer_class = self.get_object_class_path(outer_index)?;
+            if !outer_class.ends_with(".Level") {
+                packages.objects.push(ExternalObject {
+                    package_path: package_path.to_path_buf(),
+                    class_name: self.get_export_class_name(export_index)?,
+                    path: self.get_object_path(export_index as i32 + 1)?,
+                });
+                continue;
+            }
+
+            let properties = self.read_export_properties(export_index)?;
+            let actor = self.read_actor_with_properties(export_index, &properties)?;
+
+            let mut data_layers: Vec<String> = properties
+                .get("DataLayerAssets")
+                .and_then(PropertyValue::as_array)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(PropertyValue::as_object_path)
+                .collect();
+            data_layers.extend(
+                properties
+                    .get("ExternalDataLayerAsset")
+                    .and_then(PropertyValue::as_object_path),
+            );
+            data_layers.extend(
+                properties
+                    .get("DataLayers")
+                    .and_then(PropertyValue::as_array)
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|layer| layer.field("Name")?.as_str().map(str::to_string)),
+            );
+
+            packages.actors.push(ExternalActor {
+                package_path: package_path.to_path_buf(),
+                level: self.get_object_path(outer_index)?,
+                actor,
+                data_layers,
+                runtime_grid: properties
+                    .get("RuntimeGrid")
+                    .and_then(PropertyValue::as_str)
+                    .filter(|grid| *grid != "None")
+                    .map(str::to_string),
+                is_spatially_loaded: properties
+                    .get("bIsSpatiallyLoaded")
+                    .and_then(PropertyValue::as_bool)
+                    .unwrap_or(true),
+                grid_placement: properties
+                    .get("GridPlacement")
+                    .and_then(PropertyValue::as_str)
+                    .map(|value| value.rsplit("::").next().unwrap_or(value).to_string()),
+            });
+        }
+        Ok(())
+    }
+}
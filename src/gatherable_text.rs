@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub enum LocMetadataValue {
+    Boolean(bool),
+    String(String),
+    Array(Vec<LocMetadataValue>),
+    Object(LocMetadataObject),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LocMetadataObject {
+    pub values: HashMap<String, LocMetadataValue>,
+}
+
+#[derive(Debug, Default)]
+pub struct TextSourceSiteContext {
+    pub key_name: String,
+    pub site_description: String,
+    pub is_editor_only: bool,
+    pub is_optional: bool,
+    pub info_metadata: LocMetadataObject,
+    pub key_metadata: LocMetadataObject,
+}
+
+#[derive(Debug, Default)]
+pub struct GatherableTextData {
+    pub namespace_name: String,
+    pub source_string: String,
+    pub source_string_metadata: LocMetadataObject,
+    pub source_site_contexts: Vec<TextSourceSiteContext>,
+}
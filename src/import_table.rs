@@ -0,0 +1,11 @@
+use crate::unreal_types::FName;
+
+#[derive(Debug)]
+pub struct ImportEntry {
+    pub class_package: FName,
+    pub class_name: FName,
+    pub outer_index: i32,
+    pub object_name: FName,
+    pub package_name: Option<FName>,
+    pub import_optional: bool,
+}
@@ -0,0 +1,805 @@
+//! Disassembler for Blueprint Kismet bytecode (`EExprToken` streams) stored
+//! in the script of `UFunction` exports.
+//!
+//! Serialized bytecode differs from its in-memory layout: object pointers are
+//! stored as 4 byte package indices, names as 8 byte FNames and property
+//! pointers as field paths. Jump targets use in-memory offsets, so the reader
+//! tracks both positions and statements are labelled with in-memory offsets.
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::unreal_types::FName;
+use crate::versions::EUnrealEngineObjectUE5Version;
+
+// EExprToken
+const EX_LOCAL_VARIABLE: u8 = 0x00;
+const EX_INSTANCE_VARIABLE: u8 = 0x01;
+const EX_DEFAULT_VARIABLE: u8 = 0x02;
+const EX_RETURN: u8 = 0x04;
+const EX_JUMP: u8 = 0x06;
+const EX_JUMP_IF_NOT: u8 = 0x07;
+const EX_ASSERT: u8 = 0x09;
+const EX_NOTHING: u8 = 0x0B;
+const EX_NOTHING_INITIALIZED_TO: u8 = 0x0C;
+const EX_LET: u8 = 0x0F;
+const EX_BIT_FIELD_CONST: u8 = 0x11;
+const EX_CLASS_CONTEXT: u8 = 0x12;
+const EX_META_CAST: u8 = 0x13;
+const EX_LET_BOOL: u8 = 0x14;
+const EX_END_PARM_VALUE: u8 = 0x15;
+const EX_END_FUNCTION_PARMS: u8 = 0x16;
+const EX_SELF: u8 = 0x17;
+const EX_SKIP: u8 = 0x18;
+const EX_CONTEXT: u8 = 0x19;
+const EX_CONTEXT_FAIL_SILENT: u8 = 0x1A;
+const EX_VIRTUAL_FUNCTION: u8 = 0x1B;
+const EX_FINAL_FUNCTION: u8 = 0x1C;
+const EX_INT_CONST: u8 = 0x1D;
+const EX_FLOAT_CONST: u8 = 0x1E;
+const EX_STRING_CONST: u8 = 0x1F;
+const EX_OBJECT_CONST: u8 = 0x20;
+const EX_NAME_CONST: u8 = 0x21;
+const EX_ROTATION_CONST: u8 = 0x22;
+const EX_VECTOR_CONST: u8 = 0x23;
+const EX_BYTE_CONST: u8 = 0x24;
+const EX_INT_ZERO: u8 = 0x25;
+const EX_INT_ONE: u8 = 0x26;
+const EX_TRUE: u8 = 0x27;
+const EX_FALSE: u8 = 0x28;
+const EX_TEXT_CONST: u8 = 0x29;
+const EX_NO_OBJECT: u8 = 0x2A;
+const EX_TRANSFORM_CONST: u8 = 0x2B;
+const EX_INT_CONST_BYTE: u8 = 0x2C;
+const EX_NO_INTERFACE: u8 = 0x2D;
+const EX_DYNAMIC_CAST: u8 = 0x2E;
+const EX_STRUCT_CONST: u8 = 0x2F;
+const EX_END_STRUCT_CONST: u8 = 0x30;
+const EX_SET_ARRAY: u8 = 0x31;
+const EX_END_ARRAY: u8 = 0x32;
+const EX_PROPERTY_CONST: u8 = 0x33;
+const EX_UNICODE_STRING_CONST: u8 = 0x34;
+const EX_INT64_CONST: u8 = 0x35;
+const EX_UINT64_CONST: u8 = 0x36;
+const EX_DOUBLE_CONST: u8 = 0x37;
+const EX_CAST: u8 = 0x38;
+const EX_SET_SET: u8 = 0x39;
+const EX_END_SET: u8 = 0x3A;
+const EX_SET_MAP: u8 = 0x3B;
+const EX_END_MAP: u8 = 0x3C;
+const EX_SET_CONST: u8 = 0x3D;
+const EX_END_SET_CONST: u8 = 0x3E;
+const EX_MAP_CONST: u8 = 0x3F;
+const EX_END_MAP_CONST: u8 = 0x40;
+const EX_VECTOR3F_CONST: u8 = 0x41;
+const EX_STRUCT_MEMBER_CONTEXT: u8 = 0x42;
+const EX_LET_MULTICAST_DELEGATE: u8 = 0x43;
+const EX_LET_DELEGATE: u8 = 0x44;
+const EX_LOCAL_VIRTUAL_FUNCTION: u8 = 0x45;
+const EX_LOCAL_FINAL_FUNCTION: u8 = 0x46;
+const EX_LOCAL_OUT_VARIABLE: u8 = 0x48;
+const EX_DEPRECATED_OP_4A: u8 = 0x4A;
+const EX_INSTANCE_DELEGATE: u8 = 0x4B;
+const EX_PUSH_EXECUTION_FLOW: u8 = 0x4C;
+const EX_POP_EXECUTION_FLOW: u8 = 0x4D;
+const EX_COMPUTED_JUMP: u8 = 0x4E;
+const EX_POP_EXECUTION_FLOW_IF_NOT: u8 = 0x4F;
+const EX_BREAKPOINT: u8 = 0x50;
+const EX_INTERFACE_CONTEXT: u8 = 0x51;
+const EX_OBJ_TO_INTERFACE_CAST: u8 = 0x52;
+const EX_END_OF_SCRIPT: u8 = 0x53;
+const EX_CROSS_INTERFACE_CAST: u8 = 0x54;
+const EX_INTERFACE_TO_OBJ_CAST: u8 = 0x55;
+const EX_WIRE_TRACEPOINT: u8 = 0x5A;
+const EX_SKIP_OFFSET_CONST: u8 = 0x5B;
+const EX_ADD_MULTICAST_DELEGATE: u8 = 0x5C;
+const EX_CLEAR_MULTICAST_DELEGATE: u8 = 0x5D;
+const EX_TRACEPOINT: u8 = 0x5E;
+const EX_LET_OBJ: u8 = 0x5F;
+const EX_LET_WEAK_OBJ_PTR: u8 = 0x60;
+const EX_BIND_DELEGATE: u8 = 0x61;
+const EX_REMOVE_MULTICAST_DELEGATE: u8 = 0x62;
+const EX_CALL_MULTICAST_DELEGATE: u8 = 0x63;
+const EX_LET_VALUE_ON_PERSISTENT_FRAME: u8 = 0x64;
+const EX_ARRAY_CONST: u8 = 0x65;
+const EX_END_ARRAY_CONST: u8 = 0x66;
+const EX_SOFT_OBJECT_CONST: u8 = 0x67;
+const EX_CALL_MATH: u8 = 0x68;
+const EX_SWITCH_VALUE: u8 = 0x69;
+const EX_INSTRUMENTATION_EVENT: u8 = 0x6A;
+const EX_ARRAY_GET_BY_REF: u8 = 0x6B;
+const EX_CLASS_SPARSE_DATA_VARIABLE: u8 = 0x6C;
+const EX_FIELD_PATH_CONST: u8 = 0x6D;
+const EX_AUTO_RTFM_TRANSACT: u8 = 0x70;
+const EX_AUTO_RTFM_STOP_TRANSACT: u8 = 0x71;
+const EX_AUTO_RTFM_ABORT_IF_NOT: u8 = 0x72;
+
+// In-memory sizes of serialized operands
+const POINTER_SIZE: u32 = 8;
+const SCRIPT_NAME_SIZE: u32 = 12;
+
+/// Maximum nesting of expressions, guarding against corrupt bytecode.
+const MAX_EXPRESSION_DEPTH: usize = 256;
+
+/// Returns the `EExprToken` name of a bytecode token.
+pub fn token_name(token: u8) -> &'static str {
+    match token {
+        EX_LOCAL_VARIABLE => "EX_LocalVariable",
+        EX_INSTANCE_VARIABLE => "EX_InstanceVariable",
+        EX_DEFAULT_VARIABLE => "EX_DefaultVariable",
+        EX_RETURN => "EX_Return",
+        EX_JUMP => "EX_Jump",
+        EX_JUMP_IF_NOT => "EX_JumpIfNot",
+        EX_ASSERT => "EX_Assert",
+        EX_NOTHING => "EX_Nothing",
+        EX_NOTHING_INITIALIZED_TO => "EX_NothingInitializedTo",
+        EX_LET => "EX_Let",
+        EX_BIT_FIELD_CONST => "EX_BitFieldConst",
+        EX_CLASS_CONTEXT => "EX_ClassContext",
+        EX_META_CAST => "EX_MetaCast",
+        EX_LET_BOOL => "EX_LetBool",
+        EX_END_PARM_VALUE => "EX_EndParmValue",
+        EX_END_FUNCTION_PARMS => "EX_EndFunctionParms",
+        EX_SELF => "EX_Self",
+        EX_SKIP => "EX_Skip",
+        EX_CONTEXT => "EX_Context",
+        EX_CONTEXT_FAIL_SILENT => "EX_Context_FailSilent",
+        EX_VIRTUAL_FUNCTION => "EX_VirtualFunction",
+        EX_FINAL_FUNCTION => "EX_FinalFunction",
+        EX_INT_CONST => "EX_IntConst",
+        EX_FLOAT_CONST => "EX_FloatConst",
+        EX_STRING_CONST => "EX_StringConst",
+        EX_OBJECT_CONST => "EX_ObjectConst",
+        EX_NAME_CONST => "EX_NameConst",
+        EX_ROTATION_CONST => "EX_RotationConst",
+        EX_VECTOR_CONST => "EX_VectorConst",
+        EX_BYTE_CONST => "EX_ByteConst",
+        EX_INT_ZERO => "EX_IntZero",
+        EX_INT_ONE => "EX_IntOne",
+        EX_TRUE => "EX_True",
+        EX_FALSE => "EX_False",
+        EX_TEXT_CONST => "EX_TextConst",
+        EX_NO_OBJECT => "EX_NoObject",
+        EX_TRANSFORM_CONST => "EX_TransformConst",
+        EX_INT_CONST_BYTE => "EX_IntConstByte",
+        EX_NO_INTERFACE => "EX_NoInterface",
+        EX_DYNAMIC_CAST => "EX_DynamicCast",
+        EX_STRUCT_CONST => "EX_StructConst",
+        EX_END_STRUCT_CONST => "EX_EndStructConst",
+        EX_SET_ARRAY => "EX_SetArray",
+        EX_END_ARRAY => "EX_EndArray",
+        EX_PROPERTY_CONST => "EX_PropertyConst",
+        EX_UNICODE_STRING_CONST => "EX_UnicodeStringConst",
+        EX_INT64_CONST => "EX_Int64Const",
+        EX_UINT64_CONST => "EX_UInt64Const",
+        EX_DOUBLE_CONST => "EX_DoubleConst",
+        EX_CAST => "EX_Cast",
+        EX_SET_SET => "EX_SetSet",
+        EX_END_SET => "EX_EndSet",
+        EX_SET_MAP => "EX_SetMap",
+        EX_END_MAP => "EX_EndMap",
+        EX_SET_CONST => "EX_SetConst",
+        EX_END_SET_CONST => "EX_EndSetConst",
+        EX_MAP_CONST => "EX_MapConst",
+        EX_END_MAP_CONST => "EX_EndMapConst",
+        EX_VECTOR3F_CONST => "EX_Vector3fConst",
+        EX_STRUCT_MEMBER_CONTEXT => "EX_StructMemberContext",
+        EX_LET_MULTICAST_DELEGATE => "EX_LetMulticastDelegate",
+        EX_LET_DELEGATE => "EX_LetDelegate",
+        EX_LOCAL_VIRTUAL_FUNCTION => "EX_LocalVirtualFunction",
+        EX_LOCAL_FINAL_FUNCTION => "EX_LocalFinalFunction",
+        EX_LOCAL_OUT_VARIABLE => "EX_LocalOutVariable",
+        EX_DEPRECATED_OP_4A => "EX_DeprecatedOp4A",
+        EX_INSTANCE_DELEGATE => "EX_InstanceDelegate",
+        EX_PUSH_EXECUTION_FLOW => "EX_PushExecutionFlow",
+        EX_POP_EXECUTION_FLOW => "EX_PopExecutionFlow",
+        EX_COMPUTED_JUMP => "EX_ComputedJump",
+        EX_POP_EXECUTION_FLOW_IF_NOT => "EX_PopExecutionFlowIfNot",
+        EX_BREAKPOINT => "EX_Breakpoint",
+        EX_INTERFACE_CONTEXT => "EX_InterfaceContext",
+        EX_OBJ_TO_INTERFACE_CAST => "EX_ObjToInterfaceCast",
+        EX_END_OF_SCRIPT => "EX_EndOfScript",
+        EX_CROSS_INTERFACE_CAST => "EX_CrossInterfaceCast",
+        EX_INTERFACE_TO_OBJ_CAST => "EX_InterfaceToObjCast",
+        EX_WIRE_TRACEPOINT => "EX_WireTracepoint",
+        EX_SKIP_OFFSET_CONST => "EX_SkipOffsetConst",
+        EX_ADD_MULTICAST_DELEGATE => "EX_AddMulticastDelegate",
+        EX_CLEAR_MULTICAST_DELEGATE => "EX_ClearMulticastDelegate",
+        EX_TRACEPOINT => "EX_Tracepoint",
+        EX_LET_OBJ => "EX_LetObj",
+        EX_LET_WEAK_OBJ_PTR => "EX_LetWeakObjPtr",
+        EX_BIND_DELEGATE => "EX_BindDelegate",
+        EX_REMOVE_MULTICAST_DELEGATE => "EX_RemoveMulticastDelegate",
+        EX_CALL_MULTICAST_DELEGATE => "EX_CallMulticastDelegate",
+        EX_LET_VALUE_ON_PERSISTENT_FRAME => "EX_LetValueOnPersistentFrame",
+        EX_ARRAY_CONST => "EX_ArrayConst",
+        EX_END_ARRAY_CONST => "EX_EndArrayConst",
+        EX_SOFT_OBJECT_CONST => "EX_SoftObjectConst",
+        EX_CALL_MATH => "EX_CallMath",
+        EX_SWITCH_VALUE => "EX_SwitchValue",
+        EX_INSTRUMENTATION_EVENT => "EX_InstrumentationEvent",
+        EX_ARRAY_GET_BY_REF => "EX_ArrayGetByRef",
+        EX_CLASS_SPARSE_DATA_VARIABLE => "EX_ClassSparseDataVariable",
+        EX_FIELD_PATH_CONST => "EX_FieldPathConst",
+        EX_AUTO_RTFM_TRANSACT => "EX_AutoRtfmTransact",
+        EX_AUTO_RTFM_STOP_TRANSACT => "EX_AutoRtfmStopTransact",
+        EX_AUTO_RTFM_ABORT_IF_NOT => "EX_AutoRtfmAbortIfNot",
+        _ => "EX_Unknown",
+    }
+}
+
+/// A top level statement of a function's bytecode.
+#[derive(Debug, Clone)]
+pub struct KismetStatement {
+    /// In-memory offset of the statement, the unit used by jump targets.
+    pub offset: u32,
+    pub token: u8,
+    /// Readable form of the statement with names and objects resolved.
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct KismetFunction {
+    pub path: String,
+    pub statements: Vec<KismetStatement>,
+    /// Set when disassembly stopped at an unknown token or malformed operand.
+    pub error: Option<String>,
+}
+
+impl KismetFunction {
+    /// Formats the function as a listing with one statement per line.
+    pub fn listing(&self) -> String {
+        let mut listing = format!("{}\n", self.path);
+        for statement in &self.statements {
+            listing.push_str(&format!(
+                "  0x{:04X}: {:<22} {}\n",
+                statement.offset,
+                token_name(statement.token),
+                statement.text
+            ));
+        }
+        if let Some(error) = &self.error {
+            listing.push_str(&format!("  ; disassembly stopped: {}\n", error));
+        }
+        listing
+    }
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads the serialized bytecode of a struct export (0-based index into
+    /// the export table), located through the export's script serialization
+    /// offsets. Returns an empty buffer if the export has no script.
+    pub fn read_script_bytecode(&mut self, export_index: usize) -> Result<Vec<u8>> {
+        if self.summary.file_version_ue5
+            < EUnrealEngineObjectUE5Version::ScriptSerializationOffset as i32
+        {
+            return Ok(Vec::new());
+        }
+
+        let (serial_offset, start, end) = {
+            let export = self
+                .get_exports()?
+                .get(export_index)
+                .ok_or(ParseError::InvalidPackageIndex(export_index as i32 + 1))?;
+            (
+                export.serial_offset,
+                export.script_serialization_start_offset,
+                export.script_serialization_end_offset,
+            )
+        };
+
+        if end <= start || start < 0 {
+            return Ok(Vec::new());
+        }
+
+        let offset = serial_offset + start;
+        self.check_file_offset(offset)?;
+        self.check_file_offset(serial_offset + end)?;
+
+        self.reader.seek(SeekFrom::Start(offset as u64))?;
+        let mut bytecode = vec![0u8; (end - start) as usize];
+        self.reader.read_exact(&mut bytecode)?;
+        Ok(bytecode)
+    }
+
+    /// Disassembles the bytecode of a function export (0-based index into the
+    /// export table). Decoding stops at the first token it cannot decode, and
+    /// the statements read so far are kept.
+    pub fn disassemble_function(&mut self, export_index: usize) -> Result<KismetFunction> {
+        let bytecode = self.read_script_bytecode(export_index)?;
+        let path = self.get_object_path(export_index as i32 + 1)?;
+        let large_world_coordinates = self.summary.file_version_ue5
+            >= EUnrealEngineObjectUE5Version::LargeWorldCoordinates as i32;
+
+        let mut disassembler = Disassembler {
+            parser: self,
+            bytecode: &bytecode,
+            position: 0,
+            memory_offset: 0,
+            depth: 0,
+            large_world_coordinates,
+        };
+
+        let mut function = KismetFunction {
+            path,
+            ..Default::default()
+        };
+
+        while disassembler.position < bytecode.len() {
+            let offset = disassembler.memory_offset;
+            let token = bytecode[disassembler.position];
+            match disassembler.expression() {
+                Ok(text) => function.statements.push(KismetStatement {
+                    offset,
+                    token,
+                    text,
+                }),
+                Err(error) => {
+                    function.error = Some(error.to_string());
+                    break;
+                }
+            }
+            if token == EX_END_OF_SCRIPT {
+                break;
+            }
+        }
+
+        Ok(function)
+    }
+}
+
+struct Disassembler<'a, R: Read + Seek> {
+    parser: &'a mut UassetParser<R>,
+    bytecode: &'a [u8],
+    position: usize,
+    memory_offset: u32,
+    /// Nesting of the expression being read.
+    depth: usize,
+    large_world_coordinates: bool,
+}
+
+impl<R: Read + Seek> Disassembler<'_, R> {
+    fn take(&mut self, size: usize, memory_size: u32) -> Result<&[u8]> {
+        let end = self.position + size;
+        if end > self.bytecode.len() {
+            return Err(ParseError::InvalidFileOffset {
+                offset: end as i64,
+                file_size: self.bytecode.len() as u64,
+            });
+        }
+        let bytes = &self.bytecode[self.position..end];
+        self.position = end;
+        self.memory_offset += memory_size;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1, 1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(self.take(2, 2)?.read_u16::<LittleEndian>()?)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(self.take(4, 4)?.read_u32::<LittleEndian>()?)
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        Ok(self.take(4, 4)?.read_i32::<LittleEndian>()?)
+    }
+
+    fn i64(&mut self) -> Result<i64> {
+        Ok(self.take(8, 8)?.read_i64::<LittleEndian>()?)
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(self.take(8, 8)?.read_u64::<LittleEndian>()?)
+    }
+
+    fn f32(&mut self) -> Result<f32> {
+        Ok(self.take(4, 4)?.read_f32::<LittleEndian>()?)
+    }
+
+    fn f64(&mut self) -> Result<f64> {
+        Ok(self.take(8, 8)?.read_f64::<LittleEndian>()?)
+    }
+
+    /// Reads an FVector/FRotator/FQuat component.
+    fn real(&mut self) -> Result<f64> {
+        if self.large_world_coordinates {
+            self.f64()
+        } else {
+            Ok(self.f32()? as f64)
+        }
+    }
+
+    fn reals(&mut self, count: usize) -> Result<String> {
+        let mut values = Vec::with_capacity(count);
+        for _ in 0..count {
+            values.push(self.real()?.to_string());
+        }
+        Ok(values.join(", "))
+    }
+
+    fn name(&mut self) -> Result<String> {
+        let name = self.fname(SCRIPT_NAME_SIZE)?;
+        self.parser.get_name_string(&name)
+    }
+
+    fn fname(&mut self, memory_size: u32) -> Result<FName> {
+        let mut bytes = self.take(8, memory_size)?;
+        Ok(FName {
+            index: bytes.read_i32::<LittleEndian>()?,
+            number: bytes.read_i32::<LittleEndian>()?,
+        })
+    }
+
+    fn object_index(&mut self) -> Result<i32> {
+        Ok(self.take(4, POINTER_SIZE)?.read_i32::<LittleEndian>()?)
+    }
+
+    /// Reads an object reference as its full path.
+    fn object_path(&mut self) -> Result<String> {
+        let index = self.object_index()?;
+        if index == 0 {
+            return Ok("None".to_string());
+        }
+        self.parser.get_object_path(index)
+    }
+
+    /// Reads an object reference as its name, used for classes and functions.
+    fn object_name(&mut self) -> Result<String> {
+        let index = self.object_index()?;
+        self.parser.get_object_name(index)
+    }
+
+    /// Reads a property pointer, serialized as an FFieldPath.
+    fn property(&mut self) -> Result<String> {
+        let count = self.take(4, 0)?.read_i32::<LittleEndian>()?;
+        if count < 0 || count as usize > self.bytecode.len() {
+            return Err(ParseError::InvalidArraySize(count));
+        }
+        let mut path = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let name = self.fname(0)?;
+            path.push(self.parser.get_name_string(&name)?);
+        }
+        self.take(4, 0)?; // ResolvedOwner
+        self.memory_offset += POINTER_SIZE;
+
+        path.reverse();
+        Ok(if path.is_empty() {
+            "None".to_string()
+        } else {
+            path.join(".")
+        })
+    }
+
+    fn ansi_string(&mut self) -> Result<String> {
+        let length = self.bytecode[self.position..]
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or(ParseError::InvalidFileOffset {
+                offset: self.bytecode.len() as i64,
+                file_size: self.bytecode.len() as u64,
+            })?;
+        let bytes = self.take(length + 1, length as u32 + 1)?;
+        Ok(bytes[..length].iter().map(|b| *b as char).collect())
+    }
+
+    fn unicode_string(&mut self) -> Result<String> {
+        let mut units = Vec::new();
+        loop {
+            let unit = self.u16()?;
+            if unit == 0 {
+                break;
+            }
+            units.push(unit);
+        }
+        String::from_utf16(&units).map_err(|_| ParseError::InvalidUtf16)
+    }
+
+    fn peek_token(&self) -> Option<u8> {
+        self.bytecode.get(self.position).copied()
+    }
+
+    /// Reads expressions up to (and including) the given terminator token.
+    fn expressions_until(&mut self, terminator: u8) -> Result<Vec<String>> {
+        let mut expressions = Vec::new();
+        loop {
+            match self.peek_token() {
+                Some(token) if token == terminator => {
+                    self.u8()?;
+                    return Ok(expressions);
+                }
+                Some(_) => expressions.push(self.expression()?),
+                None => {
+                    return Err(ParseError::InvalidFileOffset {
+                        offset: self.position as i64,
+                        file_size: self.bytecode.len() as u64,
+                    });
+                }
+            }
+        }
+    }
+
+    fn arguments(&mut self) -> Result<String> {
+        Ok(self.expressions_until(EX_END_FUNCTION_PARMS)?.join(", "))
+    }
+
+    fn expression(&mut self) -> Result<String> {
+        if self.depth >= MAX_EXPRESSION_DEPTH {
+            return Err(ParseError::KismetExpressionTooDeep(MAX_EXPRESSION_DEPTH));
+        }
+        self.depth += 1;
+        let text = self.token_expression();
+        self.depth -= 1;
+        text
+    }
+
+    fn token_expression(&mut self) -> Result<String> {
+        let token = self.u8()?;
+
+        let text = match token {
+            EX_LOCAL_VARIABLE => self.property()?,
+            EX_INSTANCE_VARIABLE => format!("this.{}", self.property()?),
+            EX_DEFAULT_VARIABLE => format!("Default.{}", self.property()?),
+            EX_LOCAL_OUT_VARIABLE => format!("out {}", self.property()?),
+            EX_CLASS_SPARSE_DATA_VARIABLE => format!("SparseData.{}", self.property()?),
+            EX_RETURN => format!("return {}", self.expression()?),
+            EX_JUMP => format!("goto 0x{:04X}", self.u32()?),
+            EX_JUMP_IF_NOT => {
+                let target = self.u32()?;
+                format!("if !({}) goto 0x{:04X}", self.expression()?, target)
+            }
+            EX_ASSERT => {
+                let line = self.u16()?;
+                self.u8()?; // bInDebugMode
+                format!("assert({}) at line {}", self.expression()?, line)
+            }
+            EX_NOTHING | EX_DEPRECATED_OP_4A => String::new(),
+            EX_NOTHING_INITIALIZED_TO => format!("{} = default", self.property()?),
+            EX_LET => {
+                self.property()?;
+                let variable = self.expression()?;
+                format!("{} = {}", variable, self.expression()?)
+            }
+            EX_LET_BOOL
+            | EX_LET_OBJ
+            | EX_LET_WEAK_OBJ_PTR
+            | EX_LET_DELEGATE
+            | EX_LET_MULTICAST_DELEGATE => {
+                let variable = self.expression()?;
+                format!("{} = {}", variable, self.expression()?)
+            }
+            EX_LET_VALUE_ON_PERSISTENT_FRAME => {
+                let property = self.property()?;
+                format!("Frame.{} = {}", property, self.expression()?)
+            }
+            EX_BIT_FIELD_CONST => {
+                let property = self.property()?;
+                format!("{}: {}", property, self.u8()?)
+            }
+            EX_CLASS_CONTEXT | EX_CONTEXT | EX_CONTEXT_FAIL_SILENT => {
+                let object = self.expression()?;
+                self.u32()?; // skip offset for null contexts
+                self.property()?; // r-value property
+                let separator = if token == EX_CONTEXT_FAIL_SILENT {
+                    "?."
+                } else {
+                    "."
+                };
+                format!("{}{}{}", object, separator, self.expression()?)
+            }
+            EX_INTERFACE_CONTEXT => self.expression()?,
+            EX_STRUCT_MEMBER_CONTEXT => {
+                let property = self.property()?;
+                format!("{}.{}", self.expression()?, property)
+            }
+            EX_META_CAST
+            | EX_DYNAMIC_CAST
+            | EX_OBJ_TO_INTERFACE_CAST
+            | EX_CROSS_INTERFACE_CAST
+            | EX_INTERFACE_TO_OBJ_CAST => {
+                let class = self.object_name()?;
+                format!("Cast<{}>({})", class, self.expression()?)
+            }
+            EX_CAST => {
+                let conversion = self.u8()?;
+                format!("Cast#{}({})", conversion, self.expression()?)
+            }
+            EX_SELF => "this".to_string(),
+            EX_SKIP => {
+                self.u32()?;
+                self.expression()?
+            }
+            EX_VIRTUAL_FUNCTION | EX_LOCAL_VIRTUAL_FUNCTION => {
+                let function = self.name()?;
+                format!("{}({})", function, self.arguments()?)
+            }
+            EX_FINAL_FUNCTION | EX_LOCAL_FINAL_FUNCTION | EX_CALL_MATH => {
+                let function = self.object_name()?;
+                format!("{}({})", function, self.arguments()?)
+            }
+            EX_CALL_MULTICAST_DELEGATE => {
+                let signature = self.object_name()?;
+                format!("Broadcast<{}>({})", signature, self.arguments()?)
+            }
+            EX_INT_CONST => self.i32()?.to_string(),
+            EX_INT64_CONST => self.i64()?.to_string(),
+            EX_UINT64_CONST => self.u64()?.to_string(),
+            EX_FLOAT_CONST => format!("{:?}", self.f32()?),
+            EX_DOUBLE_CONST => format!("{:?}", self.f64()?),
+            EX_BYTE_CONST | EX_INT_CONST_BYTE => self.u8()?.to_string(),
+            EX_INT_ZERO => "0".to_string(),
+            EX_INT_ONE => "1".to_string(),
+            EX_TRUE => "true".to_string(),
+            EX_FALSE => "false".to_string(),
+            EX_STRING_CONST => format!("{:?}", self.ansi_string()?),
+            EX_UNICODE_STRING_CONST => format!("{:?}", self.unicode_string()?),
+            EX_NAME_CONST => format!("'{}'", self.name()?),
+            EX_OBJECT_CONST => self.object_path()?,
+            EX_NO_OBJECT | EX_NO_INTERFACE => "None".to_string(),
+            EX_SOFT_OBJECT_CONST => format!("SoftObject({})", self.expression()?),
+            EX_FIELD_PATH_CONST => format!("FieldPath({})", self.expression()?),
+            EX_PROPERTY_CONST => format!("Property({})", self.property()?),
+            EX_ROTATION_CONST => format!("Rotator({})", self.reals(3)?),
+            EX_VECTOR_CONST => format!("Vector({})", self.reals(3)?),
+            EX_VECTOR3F_CONST => {
+                let (x, y, z) = (self.f32()?, self.f32()?, self.f32()?);
+                format!("Vector3f({:?}, {:?}, {:?})", x, y, z)
+            }
+            EX_TRANSFORM_CONST => {
+                let rotation = self.reals(4)?;
+                let translation = self.reals(3)?;
+                let scale = self.reals(3)?;
+                format!(
+                    "Transform(Quat({}), Vector({}), Vector({}))",
+                    rotation, translation, scale
+                )
+            }
+            EX_TEXT_CONST => self.text_const()?,
+            EX_STRUCT_CONST => {
+                let struct_name = self.object_name()?;
+                self.i32()?; // serialized size
+                let fields = self.expressions_until(EX_END_STRUCT_CONST)?;
+                format!("{}({})", struct_name, fields.join(", "))
+            }
+            EX_SET_ARRAY => {
+                let array = self.expression()?;
+                let values = self.expressions_until(EX_END_ARRAY)?;
+                format!("{} = [{}]", array, values.join(", "))
+            }
+            EX_SET_SET => {
+                let set = self.expression()?;
+                self.i32()?;
+                let values = self.expressions_until(EX_END_SET)?;
+                format!("{} = {{{}}}", set, values.join(", "))
+            }
+            EX_SET_MAP => {
+                let map = self.expression()?;
+                self.i32()?;
+                let values = self.expressions_until(EX_END_MAP)?;
+                format!("{} = {{{}}}", map, map_entries(&values))
+            }
+            EX_ARRAY_CONST => {
+                self.property()?;
+                self.i32()?;
+                let values = self.expressions_until(EX_END_ARRAY_CONST)?;
+                format!("[{}]", values.join(", "))
+            }
+            EX_SET_CONST => {
+                self.property()?;
+                self.i32()?;
+                let values = self.expressions_until(EX_END_SET_CONST)?;
+                format!("{{{}}}", values.join(", "))
+            }
+            EX_MAP_CONST => {
+                self.property()?;
+                self.property()?;
+                self.i32()?;
+                let values = self.expressions_until(EX_END_MAP_CONST)?;
+                format!("{{{}}}", map_entries(&values))
+            }
+            EX_INSTANCE_DELEGATE => format!("Delegate({})", self.name()?),
+            EX_BIND_DELEGATE => {
+                let function = self.name()?;
+                let delegate = self.expression()?;
+                let object = self.expression()?;
+                format!("{}.Bind({}, '{}')", delegate, object, function)
+            }
+            EX_ADD_MULTICAST_DELEGATE => {
+                let delegate = self.expression()?;
+                format!("{} += {}", delegate, self.expression()?)
+            }
+            EX_REMOVE_MULTICAST_DELEGATE => {
+                let delegate = self.expression()?;
+                format!("{} -= {}", delegate, self.expression()?)
+            }
+            EX_CLEAR_MULTICAST_DELEGATE => format!("{}.Clear()", self.expression()?),
+            EX_PUSH_EXECUTION_FLOW => format!("push 0x{:04X}", self.u32()?),
+            EX_POP_EXECUTION_FLOW => "pop".to_string(),
+            EX_POP_EXECUTION_FLOW_IF_NOT => format!("if !({}) pop", self.expression()?),
+            EX_COMPUTED_JUMP => format!("goto {}", self.expression()?),
+            EX_SKIP_OFFSET_CONST => format!("0x{:04X}", self.u32()?),
+            EX_BREAKPOINT | EX_TRACEPOINT | EX_WIRE_TRACEPOINT | EX_END_OF_SCRIPT => String::new(),
+            EX_INSTRUMENTATION_EVENT => {
+                const INSTRUMENTATION_INLINE_EVENT: u8 = 4;
+                let event_type = self.u8()?;
+                if event_type == INSTRUMENTATION_INLINE_EVENT {
+                    format!("event '{}'", self.name()?)
+                } else {
+                    format!("event #{}", event_type)
+                }
+            }
+            EX_ARRAY_GET_BY_REF => {
+                let array = self.expression()?;
+                format!("{}[{}]", array, self.expression()?)
+            }
+            EX_SWITCH_VALUE => {
+                let n_cases = self.u16()?;
+                self.u32()?; // end offset
+                let index = self.expression()?;
+                let mut cases = Vec::with_capacity(n_cases as usize);
+                for _ in 0..n_cases {
+                    let value = self.expression()?;
+                    self.u32()?; // next case offset
+                    cases.push(format!("{}: {}", value, self.expression()?));
+                }
+                cases.push(format!("default: {}", self.expression()?));
+                format!("switch ({}) {{ {} }}", index, cases.join("; "))
+            }
+            EX_AUTO_RTFM_TRANSACT => {
+                let transaction = self.i32()?;
+                format!("transact #{} until 0x{:04X}", transaction, self.u32()?)
+            }
+            EX_AUTO_RTFM_STOP_TRANSACT => {
+                let transaction = self.i32()?;
+                format!("stop transact #{} mode {}", transaction, self.u8()?)
+            }
+            EX_AUTO_RTFM_ABORT_IF_NOT => format!("abort if !({})", self.expression()?),
+            other => return Err(ParseError::UnsupportedKismetToken(other)),
+        };
+
+        Ok(text)
+    }
+
+    fn text_const(&mut self) -> Result<String> {
+        // EBlueprintTextLiteralType
+        const EMPTY: u8 = 0;
+        const LOCALIZED_TEXT: u8 = 1;
+        const INVARIANT_TEXT: u8 = 2;
+        const LITERAL_STRING: u8 = 3;
+        const STRING_TABLE_ENTRY: u8 = 4;
+
+        match self.u8()? {
+            EMPTY => Ok("FText()".to_string()),
+            LOCALIZED_TEXT => {
+                let source = self.expression()?;
+                let key = self.expression()?;
+                let namespace = self.expression()?;
+                Ok(format!("NSLOCTEXT({}, {}, {})", namespace, key, source))
+            }
+            INVARIANT_TEXT => Ok(format!("INVTEXT({})", self.expression()?)),
+            LITERAL_STRING => Ok(format!("FText({})", self.expression()?)),
+            STRING_TABLE_ENTRY => {
+                self.object_index()?; // string table asset
+                let table_id = self.expression()?;
+                let key = self.expression()?;
+                Ok(format!("LOCTABLE({}, {})", table_id, key))
+            }
+            other => Err(ParseError::UnsupportedKismetToken(other)),
+        }
+    }
+}
+
+fn map_entries(values: &[String]) -> String {
+    values
+        .chunks(2)
+        .map(|pair| pair.join(": "))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
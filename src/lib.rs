@@ -1,2 +1,38 @@
+pub mod animation;
+pub mod asset_registry;
+pub mod asset_registry_state;
+pub mod curve;
+pub mod data_asset;
+pub mod data_resource;
+pub mod data_table;
+pub mod enums;
 pub mod errors;
+pub mod export_table;
+pub mod external_actors;
+pub mod gatherable_text;
+pub mod import_table;
+pub mod kismet;
+pub mod material;
+pub mod mesh;
+pub mod metadata;
+pub mod niagara;
+pub mod object_graph;
+pub mod parser;
+pub mod payload_toc;
+pub mod pixel_format;
+pub mod png;
+pub mod property;
+pub mod property_value;
+pub mod reader;
+pub mod redirector;
+pub mod schema;
+pub mod sound;
+pub mod string_table;
+pub mod summary;
+pub mod tag_value;
+pub mod texture;
+pub mod thumbnail;
+pub mod unreal_types;
+pub mod verse_cell;
 pub mod versions;
+pub mod world;
@@ -1,16 +1,9 @@
-mod asset_registry;
-mod errors;
-mod export_table;
-mod parser;
-mod reader;
-mod summary;
-mod unreal_types;
-mod versions;
-
-use crate::parser::{UassetParser, print_asset_data};
-use errors::ParseError;
 use std::fs::File;
 use std::io::BufReader;
+use std::path::PathBuf;
+use uasset_parser::asset_registry_state::{AssetRegistryState, print_asset_registry_state};
+use uasset_parser::errors::ParseError;
+use uasset_parser::parser::{PrintOptions, UassetParser, print_asset_data};
 
 fn main() -> Result<(), ParseError> {
     let args: Vec<String> = std::env::args().collect();
@@ -23,23 +16,65 @@ fn main() -> Result<(), ParseError> {
 
     let args_lower: Vec<String> = args.iter().map(|s| s.to_lowercase()).collect();
 
-    let show_asset_registry = args_lower.contains(&"-assetregistry".to_string());
-    let show_tags = args_lower.contains(&"-tags".to_string());
-    let show_names = args_lower.contains(&"-names".to_string());
-    let show_thumbnail_cache = args_lower.contains(&"-thumbnailcache".to_string());
+    let options = PrintOptions {
+        show_asset_registry: args_lower.contains(&"-assetregistry".to_string()),
+        show_tags: args_lower.contains(&"-tags".to_string()),
+        show_typed_tags: args_lower.contains(&"-typedtags".to_string()),
+        show_dependencies: args_lower.contains(&"-dependencies".to_string()),
+        show_names: args_lower.contains(&"-names".to_string()),
+        show_thumbnail_cache: args_lower.contains(&"-thumbnailcache".to_string()),
+        thumbnail_output_dir: args
+            .iter()
+            .find(|arg| arg.to_lowercase().starts_with("-extractthumbnails="))
+            .map(|arg| PathBuf::from(&arg["-extractthumbnails=".len()..])),
+        show_gatherable_text: args_lower.contains(&"-gatherabletext".to_string()),
+        show_metadata: args_lower.contains(&"-metadata".to_string()),
+        show_data_resources: args_lower.contains(&"-dataresources".to_string()),
+        show_payloads: args_lower.contains(&"-payloads".to_string()),
+        show_object_graph: args_lower.contains(&"-objectgraph".to_string()),
+        show_data_table_csv: args_lower.contains(&"-datatablecsv".to_string()),
+        show_data_table_json: args_lower.contains(&"-datatablejson".to_string()),
+        show_string_tables: args_lower.contains(&"-stringtables".to_string()),
+        show_curves: args_lower.contains(&"-curves".to_string()),
+        show_textures: args_lower.contains(&"-textures".to_string()),
+        texture_output_dir: args
+            .iter()
+            .find(|arg| arg.to_lowercase().starts_with("-extracttextures="))
+            .map(|arg| PathBuf::from(&arg["-extracttextures=".len()..])),
+        show_meshes: args_lower.contains(&"-meshes".to_string()),
+        show_kismet: args_lower.contains(&"-kismet".to_string()),
+        show_schema: args_lower.contains(&"-schema".to_string()),
+        show_enums: args_lower.contains(&"-enums".to_string()),
+        show_sounds: args_lower.contains(&"-sounds".to_string()),
+        show_materials: args_lower.contains(&"-materials".to_string()),
+        show_world: args_lower.contains(&"-world".to_string()),
+        show_animations: args_lower.contains(&"-animations".to_string()),
+        show_niagara: args_lower.contains(&"-niagara".to_string()),
+        show_redirectors: args_lower.contains(&"-redirectors".to_string()),
+        show_data_assets: args_lower.contains(&"-dataassets".to_string()),
+        redirector_content_root: args
+            .iter()
+            .find(|arg| arg.to_lowercase().starts_with("-scanredirectors="))
+            .map(|arg| PathBuf::from(&arg["-scanredirectors=".len()..])),
+        external_actors_content_root: args
+            .iter()
+            .find(|arg| arg.to_lowercase().starts_with("-externalactors="))
+            .map(|arg| PathBuf::from(&arg["-externalactors=".len()..])),
+        package_path: Some(PathBuf::from(file_path)),
+    };
 
     let file = File::open(file_path)?;
     let reader = BufReader::new(file);
 
+    if file_path.to_lowercase().ends_with(".bin") {
+        let state = AssetRegistryState::read(reader)?;
+        print_asset_registry_state(&state, options.show_tags);
+        return Ok(());
+    }
+
     let mut parser = UassetParser::new(reader, true)?;
 
-    print_asset_data(
-        &mut parser,
-        show_asset_registry,
-        show_tags,
-        show_names,
-        show_thumbnail_cache,
-    )?;
+    print_asset_data(&mut parser, &options)?;
 
     Ok(())
 }
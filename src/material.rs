@@ -0,0 +1,302 @@
+use std::io::{Read, Seek};
+
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::property_value::{Property, PropertyValue, find_property};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaterialKind {
+    Material,
+    MaterialInstance,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MaterialParameterValue {
+    Scalar(f64),
+    /// Linear color as R, G, B, A.
+    Vector([f64; 4]),
+    /// Object path of the texture, empty if none.
+    Texture(String),
+    StaticSwitch(bool),
+}
+
+/// A parameter override of a material instance, or a parameter expression
+/// with its default value in a material.
+#[derive(Debug, Clone)]
+pub struct MaterialParameter {
+    pub name: String,
+    pub value: MaterialParameterValue,
+}
+
+#[derive(Debug, Clone)]
+pub struct MaterialInfo {
+    pub kind: MaterialKind,
+    /// Parent material of an instance.
+    pub parent: Option<String>,
+    pub parameters: Vec<MaterialParameter>,
+    /// `EBlendMode` enumerator; `None` for instances that don't override it.
+    pub blend_mode: Option<String>,
+    /// `EMaterialShadingModel` enumerator; `None` for instances that don't
+    /// override it.
+    pub shading_model: Option<String>,
+    pub two_sided: Option<bool>,
+    /// Object paths of the textures the material references.
+    pub textures: Vec<String>,
+}
+
+impl MaterialInfo {
+    pub fn parameter(&self, name: &str) -> Option<&MaterialParameterValue> {
+        self.parameters
+            .iter()
+            .find(|parameter| parameter.name == name)
+            .map(|parameter| &parameter.value)
+    }
+}
+
+/// Parameter expression classes of a material.
+enum ExpressionParameter {
+    Scalar,
+    Vector,
+    StaticSwitch,
+    Texture,
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads a `Material` or `MaterialInstanceConstant` export (0-based index
+    /// into the export table).
+    ///
+    /// Instance parameters are the overrides stored on the instance. Material
+    /// parameters are the parameter expressions owned by the material, with
+    /// their default values.
+    pub fn read_material(&mut self, export_index: usize) -> Result<MaterialInfo> {
+        let class_name = self.get_export_class_name(export_index)?;
+        let kind = match class_name.as_str() {
+            "Material" => MaterialKind::Material,
+            "MaterialInstanceConstant" => MaterialKind::MaterialInstance,
+            _ => return Err(ParseError::UnexpectedExportClass(class_name)),
+        };
+
+        let properties = self.read_export_properties(export_index)?;
+        let enumerator = |value: Option<&PropertyValue>| {
+            value
+                .and_then(PropertyValue::as_str)
+                .map(|value| value.rsplit("::").next().unwrap_or(value).to_string())
+        };
+
+        let mut info = MaterialInfo {
+            kind,
+            parent: properties
+                .get("Parent")
+                .and_then(PropertyValue::as_object_path),
+            parameters: Vec::new(),
+            blend_mode: None,
+            shading_model: None,
+            two_sided: None,
+            textures: Vec::new(),
+        };
+
+        match kind {
+            MaterialKind::Material => {
+                info.blend_mode = Some(
+                    enumerator(properties.get("BlendMode"))
+                        .unwrap_or_else(|| "BLEND_Opaque".to_string()),
+                );
+                info.shading_model = Some(
+                    enumerator(properties.get("ShadingModel"))
+                        .unwrap_or_else(|| "MSM_DefaultLit".to_string()),
+                );
+                info.two_sided = Some(
+                    properties
+                        .get("TwoSided")
+                        .and_then(PropertyValue::as_bool)
+                        .unwrap_or_default(),
+                );
+                info.parameters = self.read_material_expression_parameters(export_index)?;
+            }
+            MaterialKind::MaterialInstance => {
+                if let Some(overrides) = properties.get("BasePropertyOverrides") {
+                    let overridden = |name: &str| {
+                        overrides
+                            .field(name)
+                            .and_then(PropertyValue::as_bool)
+                            .unwrap_or_default()
+                    };
+                    if overridden("bOverride_BlendMode") {
+                        info.blend_mode = enumerator(overrides.field("BlendMode"));
+                    }
+                    if overridden("bOverride_ShadingModel") {
+                        info.shading_model = enumerator(overrides.field("ShadingModel"));
+                    }
+                    if overridden("bOverride_TwoSided") {
+                        info.two_sided =
+                            overrides.field("TwoSided").and_then(PropertyValue::as_bool);
+                    }
+                }
+                info.parameters = instance_parameters(&properties.properties);
+            }
+        }
+
+        for parameter in &info.parameters {
+            if let MaterialParameterValue::Texture(path) = &parameter.value
+                && !path.is_empty()
+                && !info.textures.contains(path)
+            {
+                info.textures.push(path.clone());
+            }
+        }
+
+        let referenced_textures = properties
+            .get("CachedExpressionData")
+            .and_then(|data| data.field("ReferencedTextures"))
+            .or_else(|| properties.get("ReferencedTextures"))
+            .and_then(PropertyValue::as_array)
+            .unwrap_or_default();
+        for texture in referenced_textures {
+            if let Some(path) = texture.as_object_path()
+                && !info.textures.contains(&path)
+            {
+                info.textures.push(path);
+            }
+        }
+
+        Ok(info)
+    }
+
+    /// Reads the parameter expressions owned by a material export.
+    fn read_material_expression_parameters(
+        &mut self,
+        material_index: usize,
+    ) -> Result<Vec<MaterialParameter>> {
+        let mut parameters = Vec::new();
+        let n_exports = self.get_exports()?.len();
+        for export_index in 0..n_exports {
+            if self.get_exports()?[export_index].outer_index != material_index as i32 + 1 {
+                continue;
+            }
+
+            let class_name = self.get_export_class_name(export_index)?;
+            let kind = match class_name.as_str() {
+                "MaterialExpressionScalarParameter" => ExpressionParameter::Scalar,
+                "MaterialExpressionVectorParameter" => ExpressionParameter::Vector,
+                "MaterialExpressionStaticSwitchParameter"
+                | "MaterialExpressionStaticBoolParameter" => ExpressionParameter::StaticSwitch,
+                name if name.starts_with("MaterialExpressionTexture")
+                    && name.contains("Parameter") =>
+                {
+                    ExpressionParameter::Texture
+                }
+                _ => continue,
+            };
+
+            let expression = self.read_export_properties(export_index)?;
+            let Some(name) = expression
+                .get("ParameterName")
+                .and_then(PropertyValue::as_str)
+                .map(str::to_string)
+            else {
+                continue;
+            };
+
+            let default_value = expression.get("DefaultValue");
+            let value = match kind {
+                ExpressionParameter::Scalar => MaterialParameterValue::Scalar(
+                    default_value
+                        .and_then(PropertyValue::as_f64)
+                        .unwrap_or_default(),
+                ),
+                ExpressionParameter::Vector => MaterialParameterValue::Vector(
+                    default_value.and_then(linear_color).unwrap_or_default(),
+                ),
+                ExpressionParameter::StaticSwitch => MaterialParameterValue::StaticSwitch(
+                    default_value
+                        .and_then(PropertyValue::as_bool)
+                        .unwrap_or_default(),
+                ),
+                ExpressionParameter::Texture => MaterialParameterValue::Texture(
+                    expression
+                        .get("Texture")
+                        .and_then(PropertyValue::as_object_path)
+                        .unwrap_or_default(),
+                ),
+            };
+            parameters.push(MaterialParameter { name, value });
+        }
+        Ok(parameters)
+    }
+}
+
+/// Collects the parameter overrides of a material instance.
+fn instance_parameters(properties: &[Property]) -> Vec<MaterialParameter> {
+    let find = |name: &str| find_property(properties, name);
+    let mut parameters = Vec::new();
+
+    let mut collect =
+        |values: Option<&PropertyValue>,
+         value_of: &dyn Fn(&PropertyValue) -> Option<MaterialParameterValue>| {
+            for parameter in values.and_then(PropertyValue::as_array).unwrap_or_default() {
+                let name = parameter
+                    .field("ParameterInfo")
+                    .and_then(|info| info.field("Name"))
+                    .or_else(|| parameter.field("ParameterName"))
+                    .and_then(PropertyValue::as_str);
+                if let Some(name) = name
+                    && let Some(value) = value_of(parameter)
+                {
+                    parameters.push(MaterialParameter {
+                        name: name.to_string(),
+                        value,
+                    });
+                }
+            }
+        };
+
+    collect(find("ScalarParameterValues"), &|parameter| {
+        let value = parameter.field("ParameterValue")?.as_f64()?;
+        Some(MaterialParameterValue::Scalar(value))
+    });
+    collect(find("VectorParameterValues"), &|parameter| {
+        let value = linear_color(parameter.field("ParameterValue")?)?;
+        Some(MaterialParameterValue::Vector(value))
+    });
+    collect(find("DoubleVectorParameterValues"), &|parameter| {
+        let value = parameter.field("ParameterValue")?;
+        Some(MaterialParameterValue::Vector([
+            value.field("X")?.as_f64()?,
+            value.field("Y")?.as_f64()?,
+            value.field("Z")?.as_f64()?,
+            value.field("W")?.as_f64()?,
+        ]))
+    });
+    collect(find("TextureParameterValues"), &|parameter| {
+        let value = parameter
+            .field("ParameterValue")
+            .and_then(PropertyValue::as_object_path)
+            .unwrap_or_default();
+        Some(MaterialParameterValue::Texture(value))
+    });
+
+    // Static switches moved from `StaticParameters` to the runtime set in UE5
+    let static_switches = ["StaticParametersRuntime", "StaticParameters"]
+        .iter()
+        .filter_map(|name| find(name)?.field("StaticSwitchParameters"))
+        .find(|switches| switches.as_array().is_some_and(|array| !array.is_empty()));
+    collect(static_switches, &|parameter| {
+        let overridden = parameter
+            .field("bOverride")
+            .and_then(PropertyValue::as_bool)
+            .unwrap_or(true);
+        let value = parameter.field("Value")?.as_bool()?;
+        overridden.then_some(MaterialParameterValue::StaticSwitch(value))
+    });
+
+    parameters
+}
+
+fn linear_color(value: &PropertyValue) -> Option<[f64; 4]> {
+    Some([
+        value.field("R")?.as_f64()?,
+        value.field("G")?.as_f64()?,
+        value.field("B")?.as_f64()?,
+        value.field("A")?.as_f64()?,
+    ])
+}
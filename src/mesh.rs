@@ -0,0 +1,195 @@
+use std::io::{Read, Seek};
+
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::property_value::PropertyValue;
+
+/// `MAX_MESH_LOD_COUNT`, the most LODs a static or skeletal mesh can have.
+const MAX_MESH_LODS: u32 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeshKind {
+    Static,
+    Skeletal,
+}
+
+/// Geometry counts of a LOD, when the package records them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MeshLod {
+    pub vertices: Option<u32>,
+    pub triangles: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MaterialSlot {
+    pub slot_name: String,
+    /// Object path of the assigned material, empty if none.
+    pub material: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MeshBounds {
+    pub origin: [f64; 3],
+    pub box_extent: [f64; 3],
+    pub sphere_radius: f64,
+}
+
+/// High level stats of a `UStaticMesh` or `USkeletalMesh`, taken from the
+/// export's properties and the package's asset registry tags.
+#[derive(Debug, Clone)]
+pub struct MeshSummary {
+    pub kind: MeshKind,
+    pub lods: Vec<MeshLod>,
+    pub material_slots: Vec<MaterialSlot>,
+    pub bounds: Option<MeshBounds>,
+    pub nanite_enabled: bool,
+    pub socket_names: Vec<String>,
+}
+
+impl MeshSummary {
+    pub fn lod_count(&self) -> usize {
+        self.lods.len()
+    }
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads the summary of a `StaticMesh` or `SkeletalMesh` export (0-based
+    /// index into the export table) without decoding its render data.
+    ///
+    /// Per-LOD counts come from the cached mesh description counts of static
+    /// mesh source models; the asset registry `Vertices`/`Triangles` tags fill
+    /// in LOD 0 where those are missing.
+    pub fn read_mesh_summary(&mut self, export_index: usize) -> Result<MeshSummary> {
+        let kind = match self.get_export_class_name(export_index)?.as_str() {
+            "StaticMesh" => MeshKind::Static,
+            "SkeletalMesh" => MeshKind::Skeletal,
+            other => return Err(ParseError::UnexpectedExportClass(other.to_string())),
+        };
+
+        let properties = self.read_export_properties(export_index)?;
+        let tags = self.get_export_asset_registry_data(export_index)?;
+        let tag_count = |name: &str| {
+            tags.as_ref()
+                .and_then(|data| data.tag_value(name))
+                .and_then(|value| value.as_f64())
+                .map(|value| value as u32)
+        };
+
+        let lod_property = match kind {
+            MeshKind::Static => "SourceModels",
+            MeshKind::Skeletal => "LODInfo",
+        };
+        let mut lods: Vec<MeshLod> = properties
+            .get(lod_property)
+            .and_then(PropertyValue::as_array)
+            .unwrap_or_default()
+            .iter()
+            .map(|lod| MeshLod {
+                vertices: struct_count(lod, "CacheMeshDescriptionVerticesCount"),
+                triangles: struct_count(lod, "CacheMeshDescriptionTrianglesCount"),
+            })
+            .collect();
+
+        if lods.is_empty() {
+            let lod_count = tag_count("LODs")
+                .filter(|count| (1..=MAX_MESH_LODS).contains(count))
+                .unwrap_or(1);
+            lods.resize(lod_count as usize, MeshLod::default());
+        }
+        if lods[0].vertices.is_none() {
+            lods[0].vertices = tag_count("Vertices");
+        }
+        if lods[0].triangles.is_none() {
+            lods[0].triangles = tag_count("Triangles");
+        }
+
+        let material_property = match kind {
+            MeshKind::Static => "StaticMaterials",
+            MeshKind::Skeletal => "Materials",
+        };
+        let material_slots = properties
+            .get(material_property)
+            .and_then(PropertyValue::as_array)
+            .unwrap_or_default()
+            .iter()
+            .map(|slot| MaterialSlot {
+                slot_name: slot
+                    .field("MaterialSlotName")
+                    .and_then(PropertyValue::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                material: slot
+                    .field("MaterialInterface")
+                    .and_then(PropertyValue::as_object_path)
+                    .unwrap_or_default(),
+            })
+            .collect();
+
+        let bounds = properties
+            .get("ExtendedBounds")
+            .or_else(|| properties.get("ImportedBounds"))
+            .and_then(mesh_bounds);
+
+        let nanite_enabled = properties
+            .get("NaniteSettings")
+            .and_then(|settings| settings.field("bEnabled"))
+            .and_then(PropertyValue::as_bool)
+            .or_else(|| {
+                tags.as_ref()
+                    .and_then(|data| data.tag_value("NaniteEnabled"))
+                    .and_then(|value| value.as_bool())
+            })
+            .unwrap_or_default();
+
+        let socket_indices: Vec<i32> = properties
+            .get("Sockets")
+            .and_then(PropertyValue::as_array)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(PropertyValue::as_object_index)
+            .filter(|index| *index > 0)
+            .collect();
+
+        let mut socket_names = Vec::with_capacity(socket_indices.len());
+        for index in socket_indices {
+            let socket = self.read_export_properties(index as usize - 1)?;
+            let name = match socket.get("SocketName").and_then(PropertyValue::as_str) {
+                Some(name) => name.to_string(),
+                None => self.get_object_name(index)?,
+            };
+            socket_names.push(name);
+        }
+
+        Ok(MeshSummary {
+            kind,
+            lods,
+            material_slots,
+            bounds,
+            nanite_enabled,
+            socket_names,
+        })
+    }
+}
+
+fn struct_count(value: &PropertyValue, name: &str) -> Option<u32> {
+    value
+        .field(name)
+        .and_then(PropertyValue::as_i64)
+        .map(|count| count as u32)
+}
+
+fn mesh_bounds(value: &PropertyValue) -> Option<MeshBounds> {
+    let vector = |name: &str| -> Option<[f64; 3]> {
+        let vector = value.field(name)?;
+        Some([
+            vector.field("X")?.as_f64()?,
+            vector.field("Y")?.as_f64()?,
+            vector.field("Z")?.as_f64()?,
+        ])
+    };
+    Some(MeshBounds {
+        origin: vector("Origin")?,
+        box_extent: vector("BoxExtent")?,
+        sphere_radius: value.field("SphereRadius")?.as_f64()?,
+    })
+}
@@ -0,0 +1,9 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+pub struct PackageMetaData {
+    /// Per-object key/value pairs, keyed by the full object path.
+    pub object_metadata: HashMap<String, HashMap<String, String>>,
+    /// Package-wide key/value pairs not attached to any object.
+    pub root_metadata: HashMap<String, String>,
+}
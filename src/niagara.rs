@@ -0,0 +1,244 @@
+use std::io::{Read, Seek};
+
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::property::ObjectProperties;
+use crate::property_value::{Property, PropertyValue, find_property};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NiagaraSimTarget {
+    #[default]
+    Cpu,
+    Gpu,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NiagaraRenderer {
+    /// Renderer properties class, e.g. `NiagaraSpriteRendererProperties`.
+    pub class_name: String,
+    pub enabled: bool,
+    /// Object paths of the materials the renderer uses.
+    pub materials: Vec<String>,
+    /// Object paths of the meshes of mesh renderers.
+    pub meshes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NiagaraEmitterInfo {
+    pub name: String,
+    /// Object path of the emitter.
+    pub path: String,
+    /// Whether the emitter handle is enabled in the system; `true` for
+    /// standalone emitter assets.
+    pub enabled: bool,
+    pub sim_target: NiagaraSimTarget,
+    pub renderers: Vec<NiagaraRenderer>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NiagaraSystemInfo {
+    pub emitters: Vec<NiagaraEmitterInfo>,
+    /// Names of the user exposed parameters, e.g. `User.SpawnRate`.
+    pub user_parameters: Vec<String>,
+}
+
+impl NiagaraSystemInfo {
+    pub fn gpu_emitter_count(&self) -> usize {
+        self.emitters
+            .iter()
+            .filter(|emitter| emitter.sim_target == NiagaraSimTarget::Gpu)
+            .count()
+    }
+
+    /// Distinct material paths used by all renderers.
+    pub fn materials(&self) -> Vec<&str> {
+        let mut materials: Vec<&str> = Vec::new();
+        for renderer in self.emitters.iter().flat_map(|e| &e.renderers) {
+            for material in &renderer.materials {
+                if !materials.contains(&material.as_str()) {
+                    materials.push(material);
+                }
+            }
+        }
+        materials
+    }
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads the emitters, renderers and user parameters of a `NiagaraSystem`
+    /// export (0-based index into the export table).
+    pub fn read_niagara_system(&mut self, export_index: usize) -> Result<NiagaraSystemInfo> {
+        let class_name = self.get_export_class_name(export_index)?;
+        if class_name != "NiagaraSystem" {
+            return Err(ParseError::UnexpectedExportClass(class_name));
+        }
+
+        let properties = self.read_export_properties(export_index)?;
+        let mut info = NiagaraSystemInfo::default();
+
+        let handles = properties
+            .get("EmitterHandles")
+            .and_then(PropertyValue::as_array)
+            .unwrap_or_default();
+        for handle in handles {
+            // UE 5.1 wraps the emitter in a versioned reference
+            let emitter_index = handle
+                .field("VersionedInstance")
+                .and_then(|instance| instance.field("Emitter"))
+                .or_else(|| handle.field("Instance"))
+                .and_then(PropertyValue::as_object_index)
+                .filter(|index| *index > 0);
+            let Some(emitter_index) = emitter_index else {
+                continue;
+            };
+
+            let mut emitter = self.read_niagara_emitter(emitter_index as usize - 1)?;
+            if let Some(name) = handle.field("Name").and_then(PropertyValue::as_str) {
+                emitter.name = name.to_string();
+            }
+            emitter.enabled = handle
+                .field("bIsEnabled")
+                .and_then(PropertyValue::as_bool)
+                .unwrap_or(true);
+            info.emitters.push(emitter);
+        }
+
+        info.user_parameters = user_parameter_names(&properties);
+        Ok(info)
+    }
+
+    /// Reads the sim target and renderers of a `NiagaraEmitter` export (0-based
+    /// index into the export table).
+    pub fn read_niagara_emitter(&mut self, export_index: usize) -> Result<NiagaraEmitterInfo> {
+        let class_name = self.get_export_class_name(export_index)?;
+        if class_name != "NiagaraEmitter" {
+            return Err(ParseError::UnexpectedExportClass(class_name));
+        }
+
+        let properties = self.read_export_properties(export_index)?;
+
+        // Since UE 5.1 emitter settings live in per-version data
+        let versioned = properties
+            .get("VersionData")
+            .and_then(PropertyValue::as_array)
+            .and_then(|versions| versions.last())
+            .and_then(PropertyValue::as_struct);
+        let settings: &[Property] = versioned.unwrap_or(&properties.properties);
+
+        let sim_target = match find_property(settings, "SimTarget").and_then(PropertyValue::as_str)
+        {
+            Some(target) if target.ends_with("GPUComputeSim") => NiagaraSimTarget::Gpu,
+            _ => NiagaraSimTarget::Cpu,
+        };
+
+        let renderer_indices: Vec<i32> = find_property(settings, "RendererProperties")
+            .and_then(PropertyValue::as_array)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(PropertyValue::as_object_index)
+            .filter(|index| *index > 0)
+            .collect();
+        let mut renderers = Vec::with_capacity(renderer_indices.len());
+        for index in renderer_indices {
+            let renderer_export = index as usize - 1;
+            let renderer = self.read_export_properties(renderer_export)?;
+            renderers.push(niagara_renderer(
+                self.get_export_class_name(renderer_export)?,
+                &renderer,
+            ));
+        }
+
+        let package_index = export_index as i32 + 1;
+        Ok(NiagaraEmitterInfo {
+            name: properties
+                .get("UniqueEmitterName")
+                .and_then(PropertyValue::as_str)
+                .map(str::to_string)
+                .unwrap_or(self.get_object_name(package_index)?),
+            path: self.get_object_path(package_index)?,
+            enabled: true,
+            sim_target,
+            renderers,
+        })
+    }
+}
+
+fn niagara_renderer(class_name: String, renderer: &ObjectProperties) -> NiagaraRenderer {
+    let mut materials: Vec<String> = Vec::new();
+    let mut add_material = |path: Option<String>| {
+        if let Some(path) = path
+            && !materials.contains(&path)
+        {
+            materials.push(path);
+        }
+    };
+
+    add_material(
+        renderer
+            .get("Material")
+            .and_then(PropertyValue::as_object_path),
+    );
+    for material in renderer
+        .get("OverrideMaterials")
+        .and_then(PropertyValue::as_array)
+        .unwrap_or_default()
+    {
+        add_material(
+            material
+                .field("ExplicitMat")
+                .and_then(PropertyValue::as_object_path),
+        );
+    }
+
+    let mut meshes: Vec<String> = renderer
+        .get("Meshes")
+        .and_then(PropertyValue::as_array)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|mesh| mesh.field("Mesh")?.as_object_path())
+        .collect();
+    meshes.extend(
+        renderer
+            .get("ParticleMesh")
+            .and_then(PropertyValue::as_object_path),
+    );
+
+    NiagaraRenderer {
+        class_name,
+        enabled: renderer
+            .get("bIsEnabled")
+            .and_then(PropertyValue::as_bool)
+            .unwrap_or(true),
+        materials,
+        meshes,
+    }
+}
+
+/// Names of the parameters in a system's exposed (user) parameter store.
+fn user_parameter_names(system: &ObjectProperties) -> Vec<String> {
+    let Some(store) = system.get("ExposedParameters") else {
+        return Vec::new();
+    };
+
+    let variable_name = |variable: &PropertyValue| {
+        variable
+            .field("Name")
+            .and_then(PropertyValue::as_str)
+            .map(str::to_string)
+    };
+
+    // UE 5 keeps a sorted array of variables, UE 4 a variable to offset map
+    if let Some(variables) = store
+        .field("SortedParameterOffsets")
+        .and_then(PropertyValue::as_array)
+    {
+        return variables.iter().filter_map(variable_name).collect();
+    }
+    match store.field("ParameterOffsets") {
+        Some(PropertyValue::Map(entries)) => entries
+            .iter()
+            .filter_map(|(key, _)| variable_name(key))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,35 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectKind {
+    Import,
+    Export,
+    CellImport,
+    CellExport,
+}
+
+#[derive(Debug, Clone)]
+pub struct ObjectNode {
+    pub kind: ObjectKind,
+    /// Index into the table the object comes from.
+    pub table_index: usize,
+    pub path: String,
+    pub class_name: String,
+    /// Index of the outer object in `ObjectGraph::nodes`, `None` for top level objects.
+    pub outer: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct ObjectGraph {
+    pub nodes: Vec<ObjectNode>,
+}
+
+impl ObjectGraph {
+    pub fn of_kind(&self, kind: ObjectKind) -> impl Iterator<Item = &ObjectNode> {
+        self.nodes.iter().filter(move |node| node.kind == kind)
+    }
+
+    pub fn children(&self, node_index: usize) -> impl Iterator<Item = &ObjectNode> {
+        self.nodes
+            .iter()
+            .filter(move |node| node.outer == Some(node_index))
+    }
+}
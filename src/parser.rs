@@ -1,24 +1,56 @@
 use byteorder::{LittleEndian, ReadBytesExt};
+use std::collections::HashMap;
+use std::fs::File;
 use std::io::{Read, Seek, SeekFrom};
-
-use crate::asset_registry::{AssetData, AssetRegistryData};
+use std::path::{Path, PathBuf};
+
+use crate::asset_registry::{
+    AssetData, AssetRegistryData, AssetRegistryDependencyData, AssetRegistryPackageData,
+};
+use crate::data_resource::{
+    BULKDATA_NO_OFFSET_FIX_UP, BULKDATA_SIZE_64BIT, BulkDataLocation,
+    COMPRESSED_BUFFER_HEADER_SIZE, DataResource, EditorBulkData, compressed_buffer_size,
+    decompress_buffer,
+};
+use crate::enums::EnumDefinition;
 use crate::errors::ParseError;
 use crate::errors::Result;
 use crate::export_table::ExportEntry;
+use crate::external_actors::read_external_packages;
+use crate::gatherable_text::{
+    GatherableTextData, LocMetadataObject, LocMetadataValue, TextSourceSiteContext,
+};
+use crate::import_table::ImportEntry;
+use crate::metadata::PackageMetaData;
+use crate::object_graph::{ObjectGraph, ObjectKind, ObjectNode};
+use crate::payload_toc::{PackageTrailer, PayloadAccessMode, PayloadTocEntry};
 use crate::reader::UassetReader;
+use crate::redirector::{Redirection, RedirectorResolver};
 use crate::summary::UassetSummary;
-use crate::unreal_types::FName;
+use crate::thumbnail::{ObjectThumbnail, ThumbnailFormat};
+use crate::unreal_types::{FName, SoftObjectPath};
+use crate::verse_cell::{CellExport, CellImport};
 use crate::versions::EUnrealEngineObjectUE5Version;
 
 pub struct UassetParser<R: Read + Seek> {
-    reader: R,
-    package_file_size: u64,
+    pub(crate) reader: R,
+    pub(crate) package_file_size: u64,
     allow_unversioned: bool,
     pub summary: UassetSummary,
     names: Option<Vec<String>>,
-    asset_registry_data: Option<Vec<AssetRegistryData>>,
+    asset_registry_data: Option<AssetRegistryPackageData>,
+    soft_package_references: Option<Vec<String>>,
     thumbnail_cache: Option<Vec<AssetData>>,
-    export: Vec<ExportEntry>,
+    gatherable_text_data: Option<Vec<GatherableTextData>>,
+    soft_object_paths: Option<Vec<SoftObjectPath>>,
+    data_resources: Option<Vec<DataResource>>,
+    payload_toc: Option<PackageTrailer>,
+    metadata: Option<PackageMetaData>,
+    enums: Option<Vec<EnumDefinition>>,
+    import: Option<Vec<ImportEntry>>,
+    export: Option<Vec<ExportEntry>>,
+    cell_import: Option<Vec<CellImport>>,
+    cell_export: Option<Vec<CellExport>>,
 }
 
 impl<R: Read + Seek> UassetParser<R> {
@@ -33,8 +65,18 @@ impl<R: Read + Seek> UassetParser<R> {
             summary: UassetSummary::default(),
             names: None,
             asset_registry_data: None,
+            soft_package_references: None,
             thumbnail_cache: None,
-            export: vec![],
+            gatherable_text_data: None,
+            soft_object_paths: None,
+            data_resources: None,
+            payload_toc: None,
+            metadata: None,
+            enums: None,
+            import: None,
+            export: None,
+            cell_import: None,
+            cell_export: None,
         };
 
         parser.summary = parser.read_uasset_summary()?;
@@ -43,18 +85,47 @@ impl<R: Read + Seek> UassetParser<R> {
 
     pub fn get_names(&mut self) -> Result<&Vec<String>> {
         if self.names.is_none() {
+            // Names may be loaded in the middle of reading export data
+            let position = self.reader.stream_position()?;
             self.names = Some(self.read_names()?);
+            self.reader.seek(SeekFrom::Start(position))?;
         }
         Ok(self.names.as_ref().unwrap())
     }
 
     pub fn get_asset_registry_data(&mut self) -> Result<&Vec<AssetRegistryData>> {
+        Ok(&self.get_asset_registry_package_data()?.assets)
+    }
+
+    /// Returns the asset registry entry of an export (0-based index into the
+    /// export table), matched by object path.
+    pub fn get_export_asset_registry_data(
+        &mut self,
+        export_index: usize,
+    ) -> Result<Option<AssetRegistryData>> {
+        let path = self.get_object_path(export_index as i32 + 1)?;
+        let name = self.get_object_name(export_index as i32 + 1)?;
+        Ok(self
+            .get_asset_registry_data()?
+            .iter()
+            .find(|data| data.object_path == path || data.object_path == name)
+            .cloned())
+    }
+
+    pub fn get_asset_registry_package_data(&mut self) -> Result<&AssetRegistryPackageData> {
         if self.asset_registry_data.is_none() {
             self.asset_registry_data = Some(self.read_asset_registry_data()?);
         }
         Ok(self.asset_registry_data.as_ref().unwrap())
     }
 
+    pub fn get_soft_package_references(&mut self) -> Result<&Vec<String>> {
+        if self.soft_package_references.is_none() {
+            self.soft_package_references = Some(self.read_soft_package_references()?);
+        }
+        Ok(self.soft_package_references.as_ref().unwrap())
+    }
+
     pub fn get_thumbnail_cache(&mut self) -> Result<&Vec<AssetData>> {
         if self.thumbnail_cache.is_none() {
             self.thumbnail_cache = Some(self.read_asset_data_from_thumbnail_cache()?);
@@ -62,11 +133,561 @@ impl<R: Read + Seek> UassetParser<R> {
         Ok(self.thumbnail_cache.as_ref().unwrap())
     }
 
-    pub fn get_exports(&self) -> &Vec<ExportEntry> {
-        &self.export
+    pub fn get_gatherable_text_data(&mut self) -> Result<&Vec<GatherableTextData>> {
+        if self.gatherable_text_data.is_none() {
+            self.gatherable_text_data = Some(self.read_gatherable_text_data()?);
+        }
+        Ok(self.gatherable_text_data.as_ref().unwrap())
+    }
+
+    pub fn get_soft_object_paths(&mut self) -> Result<&Vec<SoftObjectPath>> {
+        if self.soft_object_paths.is_none() {
+            self.soft_object_paths = Some(self.read_soft_object_paths()?);
+        }
+        Ok(self.soft_object_paths.as_ref().unwrap())
+    }
+
+    pub fn get_metadata(&mut self) -> Result<&PackageMetaData> {
+        if self.metadata.is_none() {
+            self.metadata = Some(self.read_metadata()?);
+        }
+        Ok(self.metadata.as_ref().unwrap())
+    }
+
+    /// Returns the `Enum` and `UserDefinedEnum` exports of the package.
+    pub fn get_enums(&mut self) -> Result<&Vec<EnumDefinition>> {
+        if self.enums.is_none() {
+            self.enums = Some(self.read_enums()?);
+        }
+        Ok(self.enums.as_ref().unwrap())
+    }
+
+    pub fn get_data_resources(&mut self) -> Result<&Vec<DataResource>> {
+        if self.data_resources.is_none() {
+            self.data_resources = Some(self.read_data_resources()?);
+        }
+        Ok(self.data_resources.as_ref().unwrap())
+    }
+
+    /// Returns the data resources owned by an export (0-based index into the export table).
+    pub fn get_export_data_resources(&mut self, export_index: usize) -> Result<Vec<DataResource>> {
+        let outer_index = export_index as i32 + 1;
+        Ok(self
+            .get_data_resources()?
+            .iter()
+            .filter(|resource| resource.outer_index == outer_index)
+            .cloned()
+            .collect())
+    }
+
+    /// Reads the payload of a bulk data entry exactly as stored on disk.
+    ///
+    /// `package_path` is the path of the `.uasset` and is used to locate the
+    /// `.ubulk`, `.m.ubulk` or `.uptnl` file for payloads stored outside the package.
+    pub fn read_bulk_data(
+        &mut self,
+        resource: &DataResource,
+        package_path: Option<&Path>,
+    ) -> Result<Vec<u8>> {
+        if resource.serial_size < 0 {
+            return Err(ParseError::InvalidBulkDataSize(resource.serial_size));
+        }
+        if resource.is_unused() || resource.serial_size == 0 {
+            return Ok(Vec::new());
+        }
+
+        let location = resource.location();
+        let mut offset = resource.serial_offset;
+        let invalid_offset =
+            |offset: i64, file_size: u64| ParseError::InvalidFileOffset { offset, file_size };
+
+        let Some(extension) = location.file_extension() else {
+            if location == BulkDataLocation::EndOfPackage
+                && resource.legacy_bulk_data_flags & BULKDATA_NO_OFFSET_FIX_UP == 0
+            {
+                offset = offset
+                    .checked_add(self.summary.bulk_data_start_offset)
+                    .ok_or_else(|| invalid_offset(offset, self.package_file_size))?;
+            }
+
+            let end = offset
+                .checked_add(resource.serial_size)
+                .ok_or_else(|| invalid_offset(offset, self.package_file_size))?;
+            self.check_file_offset(offset)?;
+            self.check_file_offset(end)?;
+            self.reader.seek(SeekFrom::Start(offset as u64))?;
+
+            let mut payload = vec![0u8; resource.serial_size as usize];
+            self.reader.read_exact(&mut payload)?;
+            return Ok(payload);
+        };
+
+        let bulk_path = package_path
+            .map(|path| path.with_extension(extension))
+            .ok_or_else(|| ParseError::MissingBulkDataFile(extension.to_string()))?;
+
+        let mut file = File::open(&bulk_path)?;
+        let file_size = file.seek(SeekFrom::End(0))?;
+
+        let end = offset.checked_add(resource.serial_size);
+        if offset < 0 || end.is_none_or(|end| end as u64 > file_size) {
+            return Err(invalid_offset(offset, file_size));
+        }
+
+        file.seek(SeekFrom::Start(offset as u64))?;
+        let mut payload = vec![0u8; resource.serial_size as usize];
+        file.read_exact(&mut payload)?;
+        Ok(payload)
+    }
+
+    /// Reads the payload of an editor bulk data entry from the package
+    /// trailer or, for older packages, from its offset in the package.
+    pub fn read_editor_bulk_data(&mut self, bulk_data: &EditorBulkData) -> Result<Vec<u8>> {
+        if bulk_data.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let buffer = if let Some(offset) = bulk_data.offset_in_file {
+            let invalid_offset = ParseError::InvalidFileOffset {
+                offset,
+                file_size: self.package_file_size,
+            };
+            let header_end = offset
+                .checked_add(COMPRESSED_BUFFER_HEADER_SIZE as i64)
+                .ok_or(invalid_offset)?;
+            self.check_file_offset(offset)?;
+            self.check_file_offset(header_end)?;
+            self.reader.seek(SeekFrom::Start(offset as u64))?;
+            let mut header = vec![0u8; COMPRESSED_BUFFER_HEADER_SIZE];
+            self.reader.read_exact(&mut header)?;
+
+            // The whole buffer must fit in the file before allocating it
+            let size = compressed_buffer_size(&header)
+                .filter(|size| *size >= COMPRESSED_BUFFER_HEADER_SIZE as u64)
+                .ok_or(ParseError::InvalidCompressedBuffer)?;
+            let end = i64::try_from(size)
+                .ok()
+                .and_then(|size| offset.checked_add(size))
+                .ok_or(ParseError::InvalidCompressedBuffer)?;
+            self.check_file_offset(end)?;
+            let mut buffer = header;
+            buffer.resize(size as usize, 0);
+            self.reader
+                .read_exact(&mut buffer[COMPRESSED_BUFFER_HEADER_SIZE..])?;
+            buffer
+        } else {
+            let entry = self
+                .get_payload_toc()?
+                .entries
+                .iter()
+                .find(|entry| entry.identifier == bulk_data.payload_id)
+                .cloned()
+                .ok_or(ParseError::PayloadNotLocal(PayloadAccessMode::Virtualized))?;
+            self.read_payload(&entry)?
+        };
+
+        decompress_buffer(&buffer)
+    }
+
+    /// Reads a bulk data header (`FBulkData`) serialized in export data. UE5
+    /// packages with a data resource table store an index into that table;
+    /// older packages store the flags, sizes and offset inline. Inline
+    /// payloads are skipped.
+    pub(crate) fn read_bulk_data_header(&mut self) -> Result<DataResource> {
+        const BULKDATA_BAD_DATA_VERSION: u32 = 0x0000_8000;
+
+        if self.summary.file_version_ue5 >= EUnrealEngineObjectUE5Version::DataResources as i32 {
+            let index = self.reader.read_i32::<LittleEndian>()?;
+            let position = self.reader.stream_position()?;
+            let resource = self
+                .get_data_resources()?
+                .get(index as usize)
+                .filter(|_| index >= 0)
+                .cloned()
+                .ok_or(ParseError::InvalidArraySize(index))?;
+
+            let mut next = position;
+            if resource.location() == BulkDataLocation::Inline
+                && resource.serial_offset == position as i64
+            {
+                next = u64::try_from(resource.serial_size)
+                    .ok()
+                    .and_then(|size| position.checked_add(size))
+                    .ok_or(ParseError::InvalidBulkDataSize(resource.serial_size))?;
+            }
+            self.reader.seek(SeekFrom::Start(next))?;
+            return Ok(resource);
+        }
+
+        let flags = self.reader.read_u32::<LittleEndian>()?;
+        let (raw_size, serial_size) = if flags & BULKDATA_SIZE_64BIT != 0 {
+            (
+                self.reader.read_i64::<LittleEndian>()?,
+                self.reader.read_i64::<LittleEndian>()?,
+            )
+        } else {
+            (
+                self.reader.read_i32::<LittleEndian>()? as i64,
+                self.reader.read_i32::<LittleEndian>()? as i64,
+            )
+        };
+        let mut serial_offset = self.reader.read_i64::<LittleEndian>()?;
+        if flags & BULKDATA_BAD_DATA_VERSION != 0 {
+            self.reader.read_u16::<LittleEndian>()?;
+        }
+
+        let mut resource = DataResource {
+            serial_size,
+            raw_size,
+            legacy_bulk_data_flags: flags,
+            ..Default::default()
+        };
+
+        if resource.location() == BulkDataLocation::Inline {
+            let position = self.reader.stream_position()? as i64;
+            let end = position
+                .checked_add(serial_size.max(0))
+                .ok_or(ParseError::InvalidBulkDataSize(serial_size))?;
+            self.check_file_offset(end)?;
+            serial_offset = position;
+            self.reader.skip_bytes(serial_size.max(0))?;
+        }
+        resource.serial_offset = serial_offset;
+
+        Ok(resource)
+    }
+
+    /// Reads an editor bulk data header (`FEditorBulkData`) serialized in export data.
+    pub(crate) fn read_editor_bulk_data_header(&mut self) -> Result<EditorBulkData> {
+        let mut bulk_data = EditorBulkData {
+            flags: self.reader.read_u32::<LittleEndian>()?,
+            ..Default::default()
+        };
+        self.reader.read_exact(&mut bulk_data.bulk_data_id)?;
+        self.reader.read_exact(&mut bulk_data.payload_id)?;
+        bulk_data.payload_size = self.reader.read_i64::<LittleEndian>()?;
+
+        if !bulk_data.is_stored_in_package_trailer() && !bulk_data.is_virtualized() {
+            bulk_data.offset_in_file = Some(self.reader.read_i64::<LittleEndian>()?);
+        }
+
+        Ok(bulk_data)
+    }
+
+    pub fn get_payload_toc(&mut self) -> Result<&PackageTrailer> {
+        if self.payload_toc.is_none() {
+            self.payload_toc = Some(self.read_payload_toc()?);
+        }
+        Ok(self.payload_toc.as_ref().unwrap())
+    }
+
+    /// Reads a payload stored in this package's trailer. The bytes are returned
+    /// as stored, i.e. as an FCompressedBuffer.
+    pub fn read_payload(&mut self, entry: &PayloadTocEntry) -> Result<Vec<u8>> {
+        if entry.access_mode != PayloadAccessMode::Local {
+            return Err(ParseError::PayloadNotLocal(entry.access_mode));
+        }
+
+        let (trailer_offset, header_length) = {
+            let trailer = self.get_payload_toc()?;
+            (trailer.trailer_offset, trailer.header_length)
+        };
+
+        let offset = trailer_offset
+            .checked_add(header_length as i64)
+            .and_then(|offset| offset.checked_add(entry.offset_in_file))
+            .ok_or(ParseError::InvalidFileOffset {
+                offset: entry.offset_in_file,
+                file_size: self.package_file_size,
+            })?;
+        self.check_file_offset(offset)?;
+
+        // The payload must fit in the rest of the file before allocating it
+        let remaining_bytes = self.package_file_size - offset as u64;
+        if entry.compressed_size > remaining_bytes {
+            return Err(ParseError::InvalidFileOffset {
+                offset,
+                file_size: self.package_file_size,
+            });
+        }
+
+        self.reader.seek(SeekFrom::Start(offset as u64))?;
+        let mut payload = vec![0u8; entry.compressed_size as usize];
+        self.reader.read_exact(&mut payload)?;
+        Ok(payload)
+    }
+
+    pub fn get_imports(&mut self) -> Result<&Vec<ImportEntry>> {
+        if self.import.is_none() {
+            let position = self.reader.stream_position()?;
+            self.import = Some(self.read_import()?);
+            self.reader.seek(SeekFrom::Start(position))?;
+        }
+        Ok(self.import.as_ref().unwrap())
+    }
+
+    pub fn get_exports(&mut self) -> Result<&Vec<ExportEntry>> {
+        if self.export.is_none() {
+            let position = self.reader.stream_position()?;
+            self.export = Some(self.read_export()?);
+            self.reader.seek(SeekFrom::Start(position))?;
+        }
+        Ok(self.export.as_ref().unwrap())
+    }
+
+    pub fn get_cell_imports(&mut self) -> Result<&Vec<CellImport>> {
+        if self.cell_import.is_none() {
+            self.cell_import = Some(self.read_cell_import()?);
+        }
+        Ok(self.cell_import.as_ref().unwrap())
+    }
+
+    pub fn get_cell_exports(&mut self) -> Result<&Vec<CellExport>> {
+        if self.cell_export.is_none() {
+            self.cell_export = Some(self.read_cell_export()?);
+        }
+        Ok(self.cell_export.as_ref().unwrap())
+    }
+
+    /// Builds the graph of every object referenced by the package: imports,
+    /// exports and Verse cell imports/exports, linked to their outers.
+    pub fn get_object_graph(&mut self) -> Result<ObjectGraph> {
+        self.load_object_tables()?;
+        self.get_cell_imports()?;
+        self.get_cell_exports()?;
+
+        let import_count = self.import.as_ref().map_or(0, Vec::len);
+        let export_count = self.export.as_ref().map_or(0, Vec::len);
+
+        // Nodes are laid out as imports, exports, cell imports, cell exports so
+        // that an FPackageIndex maps directly to a node index.
+        // Indices outside the import and export tables have no node.
+        let node_of = |package_index: i32| -> Option<usize> {
+            match package_index {
+                0 => None,
+                index if index < 0 => {
+                    Some(index.unsigned_abs() as usize - 1).filter(|node| *node < import_count)
+                }
+                index => Some(index as usize - 1)
+                    .filter(|export| *export < export_count)
+                    .map(|export| import_count + export),
+            }
+        };
+
+        let mut graph = ObjectGraph::default();
+
+        for index in 0..import_count {
+            let package_index = -(index as i32) - 1;
+            let (class_name, outer_index) = {
+                let import = &self.import.as_ref().unwrap()[index];
+                (import.class_name.clone(), import.outer_index)
+            };
+            graph.nodes.push(ObjectNode {
+                kind: ObjectKind::Import,
+                table_index: index,
+                path: self.get_object_path(package_index)?,
+                class_name: self.resolve_name(&class_name)?,
+                outer: node_of(outer_index),
+            });
+        }
+
+        for index in 0..export_count {
+            let package_index = index as i32 + 1;
+            let (class_index, outer_index) = {
+                let export = &self.export.as_ref().unwrap()[index];
+                (export.class_index, export.outer_index)
+            };
+            graph.nodes.push(ObjectNode {
+                kind: ObjectKind::Export,
+                table_index: index,
+                path: self.get_object_path(package_index)?,
+                class_name: self.get_object_name(class_index)?,
+                outer: node_of(outer_index),
+            });
+        }
+
+        for (index, cell) in self.cell_import.as_ref().unwrap().iter().enumerate() {
+            graph.nodes.push(ObjectNode {
+                kind: ObjectKind::CellImport,
+                table_index: index,
+                path: cell.verse_path.clone(),
+                class_name: "VCell".to_string(),
+                // Cell imports live in imported packages
+                outer: node_of(cell.package_index).filter(|_| cell.package_index < 0),
+            });
+        }
+
+        for (index, cell) in self.cell_export.as_ref().unwrap().iter().enumerate() {
+            graph.nodes.push(ObjectNode {
+                kind: ObjectKind::CellExport,
+                table_index: index,
+                path: cell.verse_path.clone(),
+                class_name: cell.cpp_class_info.clone(),
+                outer: None,
+            });
+        }
+
+        Ok(graph)
+    }
+
+    /// Resolves an FName against the name table, including its number suffix.
+    pub fn get_name_string(&mut self, name: &FName) -> Result<String> {
+        self.get_names()?;
+        self.resolve_name(name)
+    }
+
+    /// Returns the object name of an FPackageIndex (positive for exports,
+    /// negative for imports), or `None` for the null index.
+    pub fn get_object_name(&mut self, package_index: i32) -> Result<String> {
+        if package_index == 0 {
+            return Ok("None".to_string());
+        }
+        self.load_object_tables()?;
+        let name = self.object_name_of(package_index)?.clone();
+        self.resolve_name(&name)
+    }
+
+    /// Returns the full object path of an FPackageIndex, e.g.
+    /// `/Game/Maps/Level.Level:PersistentLevel.Actor`.
+    pub fn get_object_path(&mut self, package_index: i32) -> Result<String> {
+        if package_index == 0 {
+            return Ok(String::new());
+        }
+        self.load_object_tables()?;
+
+        let mut chain = Vec::new();
+        let mut index = package_index;
+        while index != 0 {
+            if chain.len() > 256 {
+                return Err(ParseError::InvalidPackageIndex(package_index));
+            }
+            chain.push(self.resolve_name(self.object_name_of(index)?)?);
+            index = self.outer_index_of(index)?;
+        }
+
+        if package_index > 0 {
+            chain.push(self.summary.package_name.clone());
+        }
+        chain.reverse();
+
+        let mut path = String::new();
+        for (depth, name) in chain.iter().enumerate() {
+            match depth {
+                0 => {}
+                2 => path.push(':'),
+                _ => path.push('.'),
+            }
+            path.push_str(name);
+        }
+        Ok(path)
+    }
+
+    /// Returns the class path of an FPackageIndex, e.g. `/Script/Engine.Texture2D`,
+    /// or an empty string for the null index.
+    pub fn get_object_class_path(&mut self, package_index: i32) -> Result<String> {
+        if package_index == 0 {
+            return Ok(String::new());
+        }
+        self.load_object_tables()?;
+
+        if package_index > 0 {
+            let class_index = self
+                .export
+                .as_ref()
+                .and_then(|exports| exports.get(package_index as usize - 1))
+                .map(|export| export.class_index)
+                .ok_or(ParseError::InvalidPackageIndex(package_index))?;
+            return self.get_object_path(class_index);
+        }
+
+        let (class_package, class_name) = self
+            .import
+            .as_ref()
+            .and_then(|imports| imports.get(package_index.unsigned_abs() as usize - 1))
+            .map(|import| (import.class_package.clone(), import.class_name.clone()))
+            .ok_or(ParseError::InvalidPackageIndex(package_index))?;
+        Ok(format!(
+            "{}.{}",
+            self.resolve_name(&class_package)?,
+            self.resolve_name(&class_name)?
+        ))
+    }
+
+    /// Returns the class name of an export (0-based index into the export table).
+    pub fn get_export_class_name(&mut self, export_index: usize) -> Result<String> {
+        let class_index = self
+            .get_exports()?
+            .get(export_index)
+            .map(|export| export.class_index)
+            .ok_or(ParseError::InvalidPackageIndex(export_index as i32 + 1))?;
+        self.get_object_name(class_index)
+    }
+
+    /// Returns the indices of the exports whose class has the given name.
+    pub fn find_exports_by_class(&mut self, class_name: &str) -> Result<Vec<usize>> {
+        let count = self.get_exports()?.len();
+        let mut indices = Vec::new();
+        for index in 0..count {
+            if self.get_export_class_name(index)? == class_name {
+                indices.push(index);
+            }
+        }
+        Ok(indices)
+    }
+
+    pub(crate) fn load_object_tables(&mut self) -> Result<()> {
+        self.get_names()?;
+        self.get_imports()?;
+        self.get_exports()?;
+        Ok(())
+    }
+
+    fn resolve_name(&self, name: &FName) -> Result<String> {
+        let base = self
+            .names
+            .as_ref()
+            .and_then(|names| names.get(name.index as usize))
+            .filter(|_| name.index >= 0)
+            .ok_or(ParseError::InvalidNameIndex(name.index))?;
+
+        if name.number > 0 {
+            Ok(format!("{}_{}", base, name.number - 1))
+        } else {
+            Ok(base.clone())
+        }
+    }
+
+    fn object_name_of(&self, package_index: i32) -> Result<&FName> {
+        if package_index > 0 {
+            self.export
+                .as_ref()
+                .and_then(|exports| exports.get(package_index as usize - 1))
+                .map(|export| &export.object_name)
+        } else {
+            self.import
+                .as_ref()
+                .and_then(|imports| imports.get(package_index.unsigned_abs() as usize - 1))
+                .map(|import| &import.object_name)
+        }
+        .ok_or(ParseError::InvalidPackageIndex(package_index))
+    }
+
+    fn outer_index_of(&self, package_index: i32) -> Result<i32> {
+        if package_index > 0 {
+            self.export
+                .as_ref()
+                .and_then(|exports| exports.get(package_index as usize - 1))
+                .map(|export| export.outer_index)
+        } else {
+            self.import
+                .as_ref()
+                .and_then(|imports| imports.get(package_index.unsigned_abs() as usize - 1))
+                .map(|import| import.outer_index)
+        }
+        .ok_or(ParseError::InvalidPackageIndex(package_index))
     }
 
-    fn check_file_offset(&self, offset: i64) -> Result<()> {
+    pub(crate) fn check_file_offset(&self, offset: i64) -> Result<()> {
         if offset < 0 || offset as u64 > self.package_file_size {
             return Err(ParseError::InvalidFileOffset {
                 offset,
@@ -105,9 +726,10 @@ impl<R: Read + Seek> UassetParser<R> {
     fn read_uasset_summary(&mut self) -> Result<UassetSummary> {
         self.reader.seek(SeekFrom::Start(0))?;
 
-        let mut s = UassetSummary::default();
-
-        s.tag = self.reader.read_u32::<LittleEndian>()?;
+        let mut s = UassetSummary {
+            tag: self.reader.read_u32::<LittleEndian>()?,
+            ..Default::default()
+        };
 
         if s.tag != 0x9e2a83c1 {
             return Err(ParseError::InvalidTag);
@@ -279,9 +901,160 @@ impl<R: Read + Seek> UassetParser<R> {
         self.check_file_offset(s.asset_registry_data_offset as i64)?;
         self.check_file_offset(s.bulk_data_start_offset)?;
 
+        s.world_tile_info_data_offset = self.reader.read_i32::<LittleEndian>()?;
+
+        let current_pos = self.reader.stream_position()?;
+        let remaining_bytes = (s.total_header_size as u64).saturating_sub(current_pos + 1);
+
+        s.chunk_ids = self.reader.read_tarray(
+            |reader| Ok(reader.read_i32::<LittleEndian>()?),
+            (remaining_bytes / 4) as usize,
+        )?;
+
+        s.preload_dependency_count = self.reader.read_i32::<LittleEndian>()?;
+        s.preload_dependency_offset = self.reader.read_i32::<LittleEndian>()?;
+
+        if s.file_version_ue5 >= EUnrealEngineObjectUE5Version::NamesReferencedFromExportData as i32
+        {
+            s.names_referenced_from_export_data_count =
+                Some(self.reader.read_i32::<LittleEndian>()?);
+        }
+
+        if s.file_version_ue5 >= EUnrealEngineObjectUE5Version::PayloadToc as i32 {
+            s.payload_toc_offset = Some(self.reader.read_i64::<LittleEndian>()?);
+        }
+
+        if s.file_version_ue5 >= EUnrealEngineObjectUE5Version::DataResources as i32 {
+            let data_resource_offset = self.reader.read_i32::<LittleEndian>()?;
+            if data_resource_offset > 0 {
+                self.check_file_offset(data_resource_offset as i64)?;
+            }
+            s.data_resource_offset = Some(data_resource_offset);
+        }
+
         Ok(s)
     }
 
+    fn read_data_resources(&mut self) -> Result<Vec<DataResource>> {
+        let Some(offset) = self.summary.data_resource_offset else {
+            return Ok(Vec::new());
+        };
+
+        if offset <= 0 || offset as u64 > self.package_file_size {
+            return Ok(Vec::new());
+        }
+
+        self.reader.seek(SeekFrom::Start(offset as u64))?;
+
+        // FObjectDataResource::EVersion
+        const DATA_RESOURCE_VERSION_INITIAL: u32 = 1;
+        const DATA_RESOURCE_VERSION_ADDED_COOKED_INDEX: u32 = 2;
+        let version = self.reader.read_u32::<LittleEndian>()?;
+        if !(DATA_RESOURCE_VERSION_INITIAL..=DATA_RESOURCE_VERSION_ADDED_COOKED_INDEX)
+            .contains(&version)
+        {
+            return Err(ParseError::UnsupportedDataResourceVersion(version));
+        }
+        let has_cooked_index = version >= DATA_RESOURCE_VERSION_ADDED_COOKED_INDEX;
+        let entry_size = if has_cooked_index { 45 } else { 44 };
+
+        let remaining_bytes = self.package_file_size.saturating_sub(offset as u64);
+
+        self.reader.read_tarray(
+            |reader| {
+                Ok(DataResource {
+                    flags: reader.read_u32::<LittleEndian>()?,
+                    cooked_index: if has_cooked_index {
+                        reader.read_u8()?
+                    } else {
+                        0
+                    },
+                    serial_offset: reader.read_i64::<LittleEndian>()?,
+                    duplicate_serial_offset: reader.read_i64::<LittleEndian>()?,
+                    serial_size: reader.read_i64::<LittleEndian>()?,
+                    raw_size: reader.read_i64::<LittleEndian>()?,
+                    outer_index: reader.read_i32::<LittleEndian>()?,
+                    legacy_bulk_data_flags: reader.read_u32::<LittleEndian>()?,
+                })
+            },
+            (remaining_bytes / entry_size) as usize,
+        )
+    }
+
+    fn read_payload_toc(&mut self) -> Result<PackageTrailer> {
+        const TRAILER_HEADER_TAG: u64 = 0xD1C4_3B2E_80A5_F697;
+        // EPackageTrailerVersion
+        const TRAILER_VERSION_ACCESS_PER_PAYLOAD: i32 = 1;
+        const TRAILER_VERSION_PAYLOAD_FLAGS: i32 = 2;
+
+        let Some(offset) = self.summary.payload_toc_offset else {
+            return Ok(PackageTrailer::default());
+        };
+
+        if offset <= 0 || offset as u64 > self.package_file_size {
+            return Ok(PackageTrailer::default());
+        }
+
+        self.reader.seek(SeekFrom::Start(offset as u64))?;
+
+        if self.reader.read_u64::<LittleEndian>()? != TRAILER_HEADER_TAG {
+            return Err(ParseError::InvalidPackageTrailerTag);
+        }
+
+        let version = self.reader.read_i32::<LittleEndian>()?;
+        let header_length = self.reader.read_u32::<LittleEndian>()?;
+        let payloads_data_length = self.reader.read_u64::<LittleEndian>()?;
+
+        let remaining_bytes = self.package_file_size.saturating_sub(offset as u64);
+
+        let entries = self.reader.read_tarray(
+            |reader| {
+                let mut identifier = [0u8; 20];
+                reader.read_exact(&mut identifier)?;
+                let offset_in_file = reader.read_i64::<LittleEndian>()?;
+                let compressed_size = reader.read_u64::<LittleEndian>()?;
+                let raw_size = reader.read_u64::<LittleEndian>()?;
+
+                let (flags, filter_flags) = if version >= TRAILER_VERSION_PAYLOAD_FLAGS {
+                    (
+                        reader.read_u16::<LittleEndian>()?,
+                        reader.read_u16::<LittleEndian>()?,
+                    )
+                } else {
+                    (0, 0)
+                };
+
+                // The initial version marked virtualized payloads with a negative offset
+                let access_mode = if version >= TRAILER_VERSION_ACCESS_PER_PAYLOAD {
+                    PayloadAccessMode::from(reader.read_u8()?)
+                } else if offset_in_file < 0 {
+                    PayloadAccessMode::Virtualized
+                } else {
+                    PayloadAccessMode::Local
+                };
+
+                Ok(PayloadTocEntry {
+                    identifier,
+                    offset_in_file,
+                    compressed_size,
+                    raw_size,
+                    flags,
+                    filter_flags,
+                    access_mode,
+                })
+            },
+            (remaining_bytes / 44) as usize,
+        )?;
+
+        Ok(PackageTrailer {
+            trailer_offset: offset,
+            version,
+            header_length,
+            payloads_data_length,
+            entries,
+        })
+    }
+
     fn read_names(&mut self) -> Result<Vec<String>> {
         if self.summary.name_count <= 0 {
             return Ok(Vec::new());
@@ -305,49 +1078,143 @@ impl<R: Read + Seek> UassetParser<R> {
         Ok(names)
     }
 
-    fn read_asset_registry_data(&mut self) -> Result<Vec<AssetRegistryData>> {
+    fn read_asset_registry_data(&mut self) -> Result<AssetRegistryPackageData> {
+        const PKG_FILTER_EDITOR_ONLY: u32 = 0x8000_0000;
+
         let offset = self.summary.asset_registry_data_offset;
 
         if offset <= 0 || offset as u64 > self.package_file_size {
-            return Ok(Vec::new());
+            return Ok(AssetRegistryPackageData::default());
         }
 
         self.reader.seek(SeekFrom::Start(offset as u64))?;
 
-        let dependency_data_offset = self.reader.read_i64::<LittleEndian>()?;
-        self.check_file_offset(dependency_data_offset)?;
+        // Cooked packages are frozen at the format predating dependency data
+        let has_dependency_data = self.summary.package_flags & PKG_FILTER_EDITOR_ONLY == 0;
+
+        let dependency_data_offset = if has_dependency_data {
+            let dependency_data_offset = self.reader.read_i64::<LittleEndian>()?;
+            self.check_file_offset(dependency_data_offset)?;
+            Some(dependency_data_offset)
+        } else {
+            None
+        };
+
+        // Each asset takes at least its two strings and tag count, each tag two strings
+        let file_size = self.package_file_size;
+        let position = self.reader.stream_position()?;
+        let remaining_bytes = file_size.saturating_sub(position);
+
+        let assets = self.reader.read_tarray(
+            |reader| {
+                let object_path = reader.read_fstring()?;
+                let object_class_name = reader.read_fstring()?;
+
+                let remaining_bytes = file_size.saturating_sub(reader.stream_position()?);
+
+                let tags = reader.read_tarray(
+                    |reader| Ok((reader.read_fstring()?, reader.read_fstring()?)),
+                    (remaining_bytes / 8) as usize,
+                )?;
+
+                Ok(AssetRegistryData {
+                    object_path,
+                    object_class_name,
+                    tags: tags
+                        .into_iter()
+                        .filter(|(key, value)| !key.is_empty() && !value.is_empty())
+                        .collect(),
+                })
+            },
+            (remaining_bytes / 12) as usize,
+        )?;
+
+        let dependency_data = match dependency_data_offset {
+            Some(dependency_data_offset) if dependency_data_offset > 0 => {
+                self.reader
+                    .seek(SeekFrom::Start(dependency_data_offset as u64))?;
+                Some(self.read_asset_registry_dependency_data()?)
+            }
+            _ => None,
+        };
+
+        Ok(AssetRegistryPackageData {
+            assets,
+            dependency_data,
+        })
+    }
+
+    fn read_asset_registry_dependency_data(&mut self) -> Result<AssetRegistryDependencyData> {
+        let import_used_in_game = self.read_bit_array()?;
+        let soft_package_used_in_game = self.read_bit_array()?;
 
-        let n_assets = self.reader.read_i32::<LittleEndian>()?;
+        let mut extra_package_dependencies = Vec::new();
+        if self.summary.file_version_ue5
+            >= EUnrealEngineObjectUE5Version::AssetRegistryPackageBuildDependencies as i32
+        {
+            let position = self.reader.stream_position()?;
+            let remaining_bytes = self.package_file_size.saturating_sub(position);
 
-        if n_assets < 0 {
-            return Err(ParseError::InvalidArraySize(n_assets));
+            let dependencies = self.reader.read_tarray(
+                |reader| Ok((reader.read_fname()?, reader.read_u32::<LittleEndian>()?)),
+                (remaining_bytes / 12) as usize,
+            )?;
+
+            for (package_name, flags) in dependencies {
+                extra_package_dependencies.push((self.get_name_string(&package_name)?, flags));
+            }
         }
 
-        let mut assets = Vec::with_capacity(n_assets as usize);
+        Ok(AssetRegistryDependencyData {
+            import_used_in_game,
+            soft_package_used_in_game,
+            extra_package_dependencies,
+        })
+    }
 
-        for _ in 0..n_assets {
-            let mut asset = AssetRegistryData::default();
-            asset.object_path = self.reader.read_fstring()?;
-            asset.object_class_name = self.reader.read_fstring()?;
+    /// Reads a TBitArray: the number of bits followed by 32-bit words.
+    fn read_bit_array(&mut self) -> Result<Vec<bool>> {
+        let n_bits = self.reader.read_i32::<LittleEndian>()?;
 
-            let n_tags = self.reader.read_i32::<LittleEndian>()?;
+        let position = self.reader.stream_position()?;
+        let remaining_bits = self.package_file_size.saturating_sub(position) * 8;
 
-            for _ in 0..n_tags {
-                match (self.reader.read_fstring(), self.reader.read_fstring()) {
-                    (Ok(key), Ok(val)) => {
-                        asset.tags.insert(key, val);
-                    }
-                    _ => {
-                        assets.push(asset);
-                        return Ok(assets);
-                    }
+        if n_bits < 0 || n_bits as u64 > remaining_bits {
+            return Err(ParseError::InvalidArraySize(n_bits));
+        }
+
+        let n_words = (n_bits as usize).div_ceil(32);
+        let mut bits = Vec::with_capacity(n_bits as usize);
+        for _ in 0..n_words {
+            let word = self.reader.read_u32::<LittleEndian>()?;
+            for bit in 0..32 {
+                if bits.len() < n_bits as usize {
+                    bits.push(word & (1 << bit) != 0);
                 }
             }
+        }
+
+        Ok(bits)
+    }
 
-            assets.push(asset);
+    fn read_soft_package_references(&mut self) -> Result<Vec<String>> {
+        let offset = self.summary.soft_package_references_offset;
+        let count = self.summary.soft_package_references_count;
+
+        if offset <= 0 || offset as u64 > self.package_file_size || count <= 0 {
+            return Ok(Vec::new());
+        }
+
+        self.get_names()?;
+        self.reader.seek(SeekFrom::Start(offset as u64))?;
+
+        let mut references = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let name = self.reader.read_fname()?;
+            references.push(self.resolve_name(&name)?);
         }
 
-        Ok(assets)
+        Ok(references)
     }
 
     fn read_asset_data_from_thumbnail_cache(&mut self) -> Result<Vec<AssetData>> {
@@ -364,11 +1231,11 @@ impl<R: Read + Seek> UassetParser<R> {
         let mut asset_data_list = Vec::with_capacity(object_count as usize);
 
         for _ in 0..object_count {
-            let mut asset_data = AssetData::default();
-
-            asset_data.asset_class_name = self.reader.read_fstring()?;
-            asset_data.object_path_without_package_name = self.reader.read_fstring()?;
-            asset_data.file_offset = self.reader.read_i32::<LittleEndian>()?;
+            let asset_data = AssetData {
+                asset_class_name: self.reader.read_fstring()?,
+                object_path_without_package_name: self.reader.read_fstring()?,
+                file_offset: self.reader.read_i32::<LittleEndian>()?,
+            };
 
             asset_data_list.push(asset_data);
         }
@@ -376,13 +1243,364 @@ impl<R: Read + Seek> UassetParser<R> {
         Ok(asset_data_list)
     }
 
-    fn read_export(&mut self) -> Result<Vec<ExportEntry>> {
-        let offset = self.summary.export_offset;
-        let count = self.summary.export_count;
+    /// Reads the FObjectThumbnail an entry of the thumbnail table points at.
+    pub fn read_thumbnail(&mut self, asset_data: &AssetData) -> Result<ObjectThumbnail> {
+        self.check_file_offset(asset_data.file_offset as i64)?;
+        self.reader
+            .seek(SeekFrom::Start(asset_data.file_offset as u64))?;
 
-        if offset <= 0 || offset as u64 > self.package_file_size || count <= 0 {
-            return Ok(Vec::new());
-        }
+        let width = self.reader.read_i32::<LittleEndian>()?;
+        let height = self.reader.read_i32::<LittleEndian>()?;
+
+        let position = self.reader.stream_position()?;
+        let remaining_bytes = self.package_file_size.saturating_sub(position);
+
+        let data = self
+            .reader
+            .read_tarray(|reader| Ok(reader.read_u8()?), remaining_bytes as usize)?;
+
+        let raw_size = usize::try_from(width)
+            .ok()
+            .zip(usize::try_from(height).ok())
+            .and_then(|(width, height)| width.checked_mul(height)?.checked_mul(4));
+
+        // A negative height marks JPEG compressed thumbnails
+        let format = if data.starts_with(&[0x89, b'P', b'N', b'G']) {
+            ThumbnailFormat::Png
+        } else if height < 0 || data.starts_with(&[0xFF, 0xD8]) {
+            ThumbnailFormat::Jpeg
+        } else if raw_size == Some(data.len()) {
+            ThumbnailFormat::RawBgra
+        } else {
+            ThumbnailFormat::Png
+        };
+
+        Ok(ObjectThumbnail {
+            width,
+            height: height.abs(),
+            format,
+            data,
+        })
+    }
+
+    fn read_gatherable_text_data(&mut self) -> Result<Vec<GatherableTextData>> {
+        let offset = self.summary.gatherable_text_data_offset;
+        let count = self.summary.gatherable_text_data_count;
+
+        if offset <= 0 || offset as u64 > self.package_file_size || count <= 0 {
+            return Ok(Vec::new());
+        }
+
+        let remaining_bytes = self.package_file_size - offset as u64;
+        if count as u64 > remaining_bytes {
+            return Err(ParseError::InvalidArraySize(count));
+        }
+
+        self.reader.seek(SeekFrom::Start(offset as u64))?;
+
+        let mut entries = Vec::with_capacity(count as usize);
+
+        for _ in 0..count {
+            let namespace_name = self.reader.read_fstring()?;
+            let source_string = self.reader.read_fstring()?;
+            let source_string_metadata =
+                read_loc_metadata_object(&mut self.reader, remaining_bytes)?;
+
+            let source_site_contexts = self.reader.read_tarray(
+                |reader| {
+                    Ok(TextSourceSiteContext {
+                        key_name: reader.read_fstring()?,
+                        site_description: reader.read_fstring()?,
+                        is_editor_only: reader.read_u32::<LittleEndian>()? != 0,
+                        is_optional: reader.read_u32::<LittleEndian>()? != 0,
+                        info_metadata: read_loc_metadata_object(reader, remaining_bytes)?,
+                        key_metadata: read_loc_metadata_object(reader, remaining_bytes)?,
+                    })
+                },
+                remaining_bytes as usize,
+            )?;
+
+            entries.push(GatherableTextData {
+                namespace_name,
+                source_string,
+                source_string_metadata,
+                source_site_contexts,
+            });
+        }
+
+        Ok(entries)
+    }
+
+    fn read_soft_object_paths(&mut self) -> Result<Vec<SoftObjectPath>> {
+        let (Some(count), Some(offset)) = (
+            self.summary.soft_object_paths_count,
+            self.summary.soft_object_paths_offset,
+        ) else {
+            return Ok(Vec::new());
+        };
+
+        if offset <= 0 || offset as u64 > self.package_file_size || count <= 0 {
+            return Ok(Vec::new());
+        }
+
+        if count as u64 > self.package_file_size - offset as u64 {
+            return Err(ParseError::InvalidArraySize(count));
+        }
+
+        self.reader.seek(SeekFrom::Start(offset as u64))?;
+
+        let mut paths = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            paths.push(self.read_soft_object_path_value()?);
+        }
+
+        Ok(paths)
+    }
+
+    /// Reads an FSoftObjectPath as serialized by the linker, which stores an
+    /// index into the soft object path list when the package has one.
+    pub(crate) fn read_soft_object_path(&mut self) -> Result<SoftObjectPath> {
+        if self.summary.soft_object_paths_count.unwrap_or(0) > 0 {
+            let index = self.reader.read_i32::<LittleEndian>()?;
+            let position = self.reader.stream_position()?;
+            let path = self
+                .get_soft_object_paths()?
+                .get(index as usize)
+                .filter(|_| index >= 0)
+                .cloned()
+                .ok_or(ParseError::InvalidArraySize(index))?;
+            self.reader.seek(SeekFrom::Start(position))?;
+            return Ok(path);
+        }
+
+        self.read_soft_object_path_value()
+    }
+
+    fn read_soft_object_path_value(&mut self) -> Result<SoftObjectPath> {
+        if self.summary.file_version_ue5
+            >= EUnrealEngineObjectUE5Version::FSoftObjectPathRemoveAssetPathFNames as i32
+        {
+            let package_name = self.reader.read_fname()?;
+            let asset_name = self.reader.read_fname()?;
+            Ok(SoftObjectPath {
+                package_name: self.get_name_string(&package_name)?,
+                asset_name: self.get_name_string(&asset_name)?,
+                sub_path: self.reader.read_fstring()?,
+            })
+        } else {
+            let asset_path_name = self.reader.read_fname()?;
+            let asset_path_name = self.get_name_string(&asset_path_name)?;
+            let (package_name, asset_name) = match asset_path_name.split_once('.') {
+                Some((package_name, asset_name)) => {
+                    (package_name.to_string(), asset_name.to_string())
+                }
+                None => (asset_path_name, String::new()),
+            };
+            Ok(SoftObjectPath {
+                package_name,
+                asset_name,
+                sub_path: self.reader.read_fstring()?,
+            })
+        }
+    }
+
+    fn read_metadata(&mut self) -> Result<PackageMetaData> {
+        self.load_object_tables()?;
+        self.get_soft_object_paths()?;
+
+        let mut metadata = PackageMetaData::default();
+
+        if let Some(offset) = self.summary.metadata_offset.filter(|offset| *offset > 0) {
+            self.check_file_offset(offset as i64)?;
+            self.reader.seek(SeekFrom::Start(offset as u64))?;
+
+            let n_object_metadata = self.reader.read_i64::<LittleEndian>()?;
+            let n_root_metadata = self.reader.read_i64::<LittleEndian>()?;
+
+            if n_object_metadata < 0 || n_object_metadata > i32::MAX as i64 {
+                return Err(ParseError::InvalidArraySize(n_object_metadata as i32));
+            }
+            if n_root_metadata < 0 || n_root_metadata > i32::MAX as i64 {
+                return Err(ParseError::InvalidArraySize(n_root_metadata as i32));
+            }
+
+            for _ in 0..n_object_metadata {
+                let object_path = self.read_soft_object_path()?.to_string();
+                let values = self.read_name_string_map()?;
+                metadata.object_metadata.insert(object_path, values);
+            }
+
+            for _ in 0..n_root_metadata {
+                let key = self.reader.read_fname()?;
+                let key = self.get_name_string(&key)?;
+                metadata
+                    .root_metadata
+                    .insert(key, self.reader.read_fstring()?);
+            }
+
+            return Ok(metadata);
+        }
+
+        // Older packages store the metadata as a UMetaData export
+        let Some(&export_index) = self.find_exports_by_class("MetaData")?.first() else {
+            return Ok(metadata);
+        };
+
+        self.seek_export_properties(export_index)?;
+
+        let n_object_metadata = self.reader.read_i32::<LittleEndian>()?;
+        if n_object_metadata < 0 {
+            return Err(ParseError::InvalidArraySize(n_object_metadata));
+        }
+
+        for _ in 0..n_object_metadata {
+            let object_index = self.reader.read_i32::<LittleEndian>()?;
+            let values = self.read_name_string_map()?;
+            let object_path = self.get_object_path(object_index)?;
+            metadata.object_metadata.insert(object_path, values);
+        }
+
+        metadata.root_metadata = self.read_name_string_map()?;
+
+        Ok(metadata)
+    }
+
+    fn read_name_string_map(&mut self) -> Result<HashMap<String, String>> {
+        let n_values = self.read_element_count()?;
+
+        let mut values = HashMap::with_capacity(n_values);
+        for _ in 0..n_values {
+            let key = self.reader.read_fname()?;
+            let key = self.get_name_string(&key)?;
+            values.insert(key, self.reader.read_fstring()?);
+        }
+
+        Ok(values)
+    }
+
+    fn read_import(&mut self) -> Result<Vec<ImportEntry>> {
+        const PKG_FILTER_EDITOR_ONLY: u32 = 0x8000_0000;
+
+        let offset = self.summary.import_offset;
+        let count = self.summary.import_count;
+
+        if offset <= 0 || offset as u64 > self.package_file_size || count <= 0 {
+            return Ok(Vec::new());
+        }
+        if count as u64 > self.package_file_size - offset as u64 {
+            return Err(ParseError::InvalidArraySize(count));
+        }
+
+        let mut entries = Vec::with_capacity(count as usize);
+
+        self.reader.seek(SeekFrom::Start(offset as u64))?;
+        for _ in 0..count {
+            let class_package = self.reader.read_fname()?;
+            let class_name = self.reader.read_fname()?;
+            let outer_index = self.reader.read_i32::<LittleEndian>()?;
+            let object_name = self.reader.read_fname()?;
+
+            let package_name = if self.summary.package_flags & PKG_FILTER_EDITOR_ONLY == 0 {
+                Some(self.reader.read_fname()?)
+            } else {
+                None
+            };
+
+            let import_optional = if self.summary.file_version_ue5
+                >= EUnrealEngineObjectUE5Version::OptionalResources as i32
+            {
+                self.reader.read_u32::<LittleEndian>()? != 0
+            } else {
+                false
+            };
+
+            entries.push(ImportEntry {
+                class_package,
+                class_name,
+                outer_index,
+                object_name,
+                package_name,
+                import_optional,
+            });
+        }
+
+        Ok(entries)
+    }
+
+    fn read_cell_import(&mut self) -> Result<Vec<CellImport>> {
+        let (Some(offset), Some(count)) = (
+            self.summary.cell_import_offset,
+            self.summary.cell_import_count,
+        ) else {
+            return Ok(Vec::new());
+        };
+
+        if offset <= 0 || offset as u64 > self.package_file_size || count <= 0 {
+            return Ok(Vec::new());
+        }
+        if count as u64 > self.package_file_size - offset as u64 {
+            return Err(ParseError::InvalidArraySize(count));
+        }
+
+        let mut entries = Vec::with_capacity(count as usize);
+
+        self.reader.seek(SeekFrom::Start(offset as u64))?;
+        for _ in 0..count {
+            entries.push(CellImport {
+                package_index: self.reader.read_i32::<LittleEndian>()?,
+                verse_path: self.reader.read_fstring()?,
+            });
+        }
+
+        Ok(entries)
+    }
+
+    fn read_cell_export(&mut self) -> Result<Vec<CellExport>> {
+        let (Some(offset), Some(count)) = (
+            self.summary.cell_export_offset,
+            self.summary.cell_export_count,
+        ) else {
+            return Ok(Vec::new());
+        };
+
+        if offset <= 0 || offset as u64 > self.package_file_size || count <= 0 {
+            return Ok(Vec::new());
+        }
+        if count as u64 > self.package_file_size - offset as u64 {
+            return Err(ParseError::InvalidArraySize(count));
+        }
+
+        self.get_names()?;
+
+        let mut entries = Vec::with_capacity(count as usize);
+
+        self.reader.seek(SeekFrom::Start(offset as u64))?;
+        for _ in 0..count {
+            let cpp_class_info = self.reader.read_fname()?;
+            entries.push(CellExport {
+                cpp_class_info: self.resolve_name(&cpp_class_info)?,
+                verse_path: self.reader.read_fstring()?,
+                serial_offset: self.reader.read_i64::<LittleEndian>()?,
+                serial_layout_size: self.reader.read_i64::<LittleEndian>()?,
+                serial_size: self.reader.read_i64::<LittleEndian>()?,
+                first_export_dependency: self.reader.read_i32::<LittleEndian>()?,
+                serialization_before_serialization_dependencies: self
+                    .reader
+                    .read_i32::<LittleEndian>()?,
+                create_before_serialization_dependencies: self.reader.read_i32::<LittleEndian>()?,
+            });
+        }
+
+        Ok(entries)
+    }
+
+    fn read_export(&mut self) -> Result<Vec<ExportEntry>> {
+        let offset = self.summary.export_offset;
+        let count = self.summary.export_count;
+
+        if offset <= 0 || offset as u64 > self.package_file_size || count <= 0 {
+            return Ok(Vec::new());
+        }
 
         let mut entries: Vec<ExportEntry> = vec![];
 
@@ -408,7 +1626,7 @@ impl<R: Read + Seek> UassetParser<R> {
             }
 
             let is_inherited_instance = if self.summary.file_version_ue5
-                > EUnrealEngineObjectUE5Version::TrackObjectExportIsInherited as i32
+                >= EUnrealEngineObjectUE5Version::TrackObjectExportIsInherited as i32
             {
                 self.reader.read_u32::<LittleEndian>()? != 0
             } else {
@@ -436,8 +1654,17 @@ impl<R: Read + Seek> UassetParser<R> {
                 self.reader.read_i32::<LittleEndian>()?;
             let create_before_create_dependencies = self.reader.read_i32::<LittleEndian>()?;
 
-            let script_serialization_start_offset = self.reader.read_i64::<LittleEndian>()?;
-            let script_serialization_end_offset = self.reader.read_i64::<LittleEndian>()?;
+            let (script_serialization_start_offset, script_serialization_end_offset) =
+                if self.summary.file_version_ue5
+                    >= EUnrealEngineObjectUE5Version::ScriptSerializationOffset as i32
+                {
+                    (
+                        self.reader.read_i64::<LittleEndian>()?,
+                        self.reader.read_i64::<LittleEndian>()?,
+                    )
+                } else {
+                    (0, 0)
+                };
 
             let entry = ExportEntry {
                 class_index,
@@ -472,33 +1699,148 @@ impl<R: Read + Seek> UassetParser<R> {
     }
 }
 
+/// Reads an `FLocMetadataObject`; `max_elements` bounds its value counts.
+fn read_loc_metadata_object<R: Read + Seek>(
+    reader: &mut R,
+    max_elements: u64,
+) -> Result<LocMetadataObject> {
+    let n_values = reader.read_i32::<LittleEndian>()?;
+    if n_values < 0 || n_values as u64 > max_elements {
+        return Err(ParseError::InvalidArraySize(n_values));
+    }
+
+    let mut object = LocMetadataObject::default();
+    for _ in 0..n_values {
+        let key = reader.read_fstring()?;
+        let value = read_loc_metadata_value(reader, max_elements)?;
+        object.values.insert(key, value);
+    }
+
+    Ok(object)
+}
+
+fn read_loc_metadata_value<R: Read + Seek>(
+    reader: &mut R,
+    max_elements: u64,
+) -> Result<LocMetadataValue> {
+    // ELocMetadataType: None, Boolean, String, Array, Object
+    let value_type = reader.read_i32::<LittleEndian>()?;
+
+    match value_type {
+        1 => Ok(LocMetadataValue::Boolean(
+            reader.read_u32::<LittleEndian>()? != 0,
+        )),
+        2 => Ok(LocMetadataValue::String(reader.read_fstring()?)),
+        3 => Ok(LocMetadataValue::Array(reader.read_tarray(
+            |reader| read_loc_metadata_value(reader, max_elements),
+            max_elements as usize,
+        )?)),
+        4 => Ok(LocMetadataValue::Object(read_loc_metadata_object(
+            reader,
+            max_elements,
+        )?)),
+        _ => Err(ParseError::InvalidLocMetadataType(value_type)),
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PrintOptions {
+    pub show_asset_registry: bool,
+    pub show_tags: bool,
+    pub show_typed_tags: bool,
+    pub show_dependencies: bool,
+    pub show_names: bool,
+    pub show_thumbnail_cache: bool,
+    pub thumbnail_output_dir: Option<PathBuf>,
+    pub show_gatherable_text: bool,
+    pub show_metadata: bool,
+    pub show_data_resources: bool,
+    pub show_payloads: bool,
+    pub show_object_graph: bool,
+    pub show_data_table_csv: bool,
+    pub show_data_table_json: bool,
+    pub show_string_tables: bool,
+    pub show_curves: bool,
+    pub show_textures: bool,
+    pub texture_output_dir: Option<PathBuf>,
+    pub show_meshes: bool,
+    pub show_kismet: bool,
+    pub show_schema: bool,
+    pub show_enums: bool,
+    pub show_sounds: bool,
+    pub show_materials: bool,
+    pub show_world: bool,
+    pub show_animations: bool,
+    pub show_niagara: bool,
+    pub show_redirectors: bool,
+    pub show_data_assets: bool,
+    /// Content folder to scan for broken redirector chains.
+    pub redirector_content_root: Option<PathBuf>,
+    /// Content folder of the map, used to find its external actor packages.
+    pub external_actors_content_root: Option<PathBuf>,
+    /// Path of the package, used to locate `.ubulk` files.
+    pub package_path: Option<PathBuf>,
+}
+
 pub fn print_asset_data(
     parser: &mut UassetParser<impl Read + Seek>,
-    show_asset_registry: bool,
-    show_tags: bool,
-    show_names: bool,
-    show_thumbnail_cache: bool,
+    options: &PrintOptions,
 ) -> Result<()> {
     // Print summary
     println!("{:#?}", parser.summary);
 
-    if show_asset_registry {
+    if options.show_asset_registry {
         let registry_data = parser.get_asset_registry_data()?;
         for (idx, asset_data) in registry_data.iter().enumerate() {
             println!("\nAssetData {}\n", idx);
             println!("ObjectPath     : {}", asset_data.object_path);
             println!("ObjectClassName: {}", asset_data.object_class_name);
 
-            if show_tags {
+            if options.show_tags {
                 println!("Tags");
                 for (k, v) in &asset_data.tags {
                     println!("Tag {}: {}", k, v);
                 }
             }
+
+            if options.show_typed_tags {
+                println!("TypedTags");
+                for k in asset_data.tags.keys() {
+                    println!("Tag {}: {:?}", k, asset_data.tag_value(k).unwrap());
+                }
+            }
         }
     }
 
-    if show_names {
+    if options.show_dependencies {
+        let dependency_data = parser
+            .get_asset_registry_package_data()?
+            .dependency_data
+            .clone()
+            .unwrap_or_default();
+
+        println!("\nImports used in game\n");
+        for (idx, used_in_game) in dependency_data.import_used_in_game.iter().enumerate() {
+            let path = parser.get_object_path(-(idx as i32) - 1)?;
+            println!("{}: {}", path, used_in_game);
+        }
+
+        println!("\nSoft packages used in game\n");
+        let soft_package_references = parser.get_soft_package_references()?;
+        for (name, used_in_game) in soft_package_references
+            .iter()
+            .zip(&dependency_data.soft_package_used_in_game)
+        {
+            println!("{}: {}", name, used_in_game);
+        }
+
+        println!("\nExtra package dependencies\n");
+        for (package_name, flags) in &dependency_data.extra_package_dependencies {
+            println!("{}: {:#x}", package_name, flags);
+        }
+    }
+
+    if options.show_names {
         println!("\nNames\n");
         let names = parser.get_names()?;
         for (idx, name) in names.iter().enumerate() {
@@ -506,7 +1848,7 @@ pub fn print_asset_data(
         }
     }
 
-    if show_thumbnail_cache {
+    if options.show_thumbnail_cache {
         println!("\nThumbnailCache");
         let cache = parser.get_thumbnail_cache()?;
         for asset_data in cache {
@@ -523,7 +1865,506 @@ pub fn print_asset_data(
         }
     }
 
-    let exports = parser.read_export().unwrap();
+    if let Some(output_dir) = &options.thumbnail_output_dir {
+        let cache = parser.get_thumbnail_cache()?.clone();
+        for asset_data in &cache {
+            let thumbnail = parser.read_thumbnail(asset_data)?;
+            if thumbnail.is_empty() {
+                continue;
+            }
+
+            let file_name: String = asset_data
+                .object_path_without_package_name
+                .chars()
+                .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                .collect();
+            let path = output_dir.join(file_name);
+            thumbnail.save(&path)?;
+            println!(
+                "Thumbnail {}x{} written to {}",
+                thumbnail.width,
+                thumbnail.height,
+                path.with_extension(thumbnail.file_extension()).display()
+            );
+        }
+    }
+
+    if options.show_gatherable_text {
+        println!("\nGatherableTextData");
+        let entries = parser.get_gatherable_text_data()?;
+        for entry in entries {
+            println!();
+            println!("Namespace   : {}", entry.namespace_name);
+            println!("SourceString: {}", entry.source_string);
+            for context in &entry.source_site_contexts {
+                println!(
+                    "  Key: {}, Site: {}, EditorOnly: {}, Optional: {}",
+                    context.key_name,
+                    context.site_description,
+                    context.is_editor_only,
+                    context.is_optional
+                );
+            }
+        }
+    }
+
+    if options.show_metadata {
+        println!("\nMetaData");
+        let metadata = parser.get_metadata()?;
+        for (object_path, values) in &metadata.object_metadata {
+            println!("\n{}", object_path);
+            for (k, v) in values {
+                println!("  {}: {}", k, v);
+            }
+        }
+        if !metadata.root_metadata.is_empty() {
+            println!("\nRoot");
+            for (k, v) in &metadata.root_metadata {
+                println!("  {}: {}", k, v);
+            }
+        }
+    }
+
+    if options.show_data_resources {
+        println!("\nDataResources");
+        let resources = parser.get_data_resources()?.clone();
+        for (idx, resource) in resources.iter().enumerate() {
+            println!();
+            println!("DataResource {}", idx);
+            println!(
+                "Owner       : {}",
+                parser.get_object_path(resource.outer_index)?
+            );
+            println!("Location    : {:?}", resource.location());
+            println!("SerialOffset: {}", resource.serial_offset);
+            println!("SerialSize  : {}", resource.serial_size);
+            println!("RawSize     : {}", resource.raw_size);
+            println!("BulkFlags   : {:#010x}", resource.legacy_bulk_data_flags);
+        }
+    }
+
+    if options.show_payloads {
+        println!("\nPayloadToc");
+        let trailer = parser.get_payload_toc()?;
+        for entry in &trailer.entries {
+            println!();
+            println!("PayloadId     : {}", entry.identifier_hex());
+            println!("AccessMode    : {:?}", entry.access_mode);
+            println!("CompressedSize: {}", entry.compressed_size);
+            println!("RawSize       : {}", entry.raw_size);
+        }
+        println!();
+        println!("LocalRawSize      : {}", trailer.local_raw_size());
+        println!("VirtualizedRawSize: {}", trailer.virtualized_raw_size());
+    }
+
+    if options.show_object_graph {
+        println!("\nObjectGraph\n");
+        let graph = parser.get_object_graph()?;
+        for node in &graph.nodes {
+            println!(
+                "{:?} {}: {} ({})",
+                node.kind, node.table_index, node.path, node.class_name
+            );
+        }
+    }
+
+    if options.show_data_table_csv || options.show_data_table_json {
+        let mut tables = parser.find_exports_by_class("DataTable")?;
+        tables.extend(parser.find_exports_by_class("CompositeDataTable")?);
+        for export_index in tables {
+            let table = parser.read_data_table(export_index)?;
+            println!("\nDataTable {} ({})\n", export_index, table.row_struct);
+            if options.show_data_table_csv {
+                print!("{}", table.to_csv());
+            }
+            if options.show_data_table_json {
+                print!("{}", table.to_json());
+            }
+        }
+    }
+
+    if options.show_string_tables {
+        for export_index in parser.find_exports_by_class("StringTable")? {
+            let table = parser.read_string_table(export_index)?;
+            println!("\nStringTable {} ({})\n", export_index, table.namespace);
+            print!("{}", table.to_csv());
+        }
+    }
+
+    if options.show_curves {
+        for export_index in parser.find_exports_by_class("CurveTable")? {
+            let table = parser.read_curve_table(export_index)?;
+            println!("\nCurveTable {} ({:?})\n", export_index, table.mode);
+            print!("{}", table.to_csv());
+        }
+
+        for class_name in ["CurveFloat", "CurveVector", "CurveLinearColor"] {
+            for export_index in parser.find_exports_by_class(class_name)? {
+                println!("\n{} {}", class_name, export_index);
+                for (name, curve) in parser.read_curves(export_index)? {
+                    println!("  {}: {:?}", name, curve.points());
+                }
+            }
+        }
+    }
+
+    if options.show_textures || options.texture_output_dir.is_some() {
+        for export_index in parser.find_exports_by_class("Texture2D")? {
+            let texture = parser.read_texture_2d(export_index)?;
+            let (size_x, size_y) = texture.size();
+            println!(
+                "\nTexture2D {}\n",
+                parser.get_object_path(export_index as i32 + 1)?
+            );
+            println!("Size               : {}x{}", size_x, size_y);
+            println!("Mips               : {}", texture.mip_count());
+            println!(
+                "PixelFormat        : {}",
+                texture.pixel_format().unwrap_or("None")
+            );
+            println!("LODGroup           : {}", texture.lod_group);
+            println!("CompressionSettings: {}", texture.compression_settings);
+            println!("SRGB               : {}", texture.srgb);
+            if let Some(source) = &texture.source {
+                println!(
+                    "Source             : {}x{} {} ({})",
+                    source.size_x, source.size_y, source.format, source.compression_format
+                );
+            }
+
+            if let Some(output_dir) = &options.texture_output_dir {
+                let name = parser.get_object_name(export_index as i32 + 1)?;
+                match parser.export_texture_image(&texture, options.package_path.as_deref()) {
+                    Ok((image, extension)) => {
+                        let path = output_dir.join(name).with_extension(extension);
+                        std::fs::write(&path, image)?;
+                        println!("Written to         : {}", path.display());
+                    }
+                    Err(error) => println!("Not exported       : {}", error),
+                }
+            }
+        }
+    }
+
+    if options.show_meshes {
+        let mut meshes = parser.find_exports_by_class("StaticMesh")?;
+        meshes.extend(parser.find_exports_by_class("SkeletalMesh")?);
+        for export_index in meshes {
+            let summary = parser.read_mesh_summary(export_index)?;
+            println!(
+                "\n{:?}Mesh {}\n",
+                summary.kind,
+                parser.get_object_path(export_index as i32 + 1)?
+            );
+            for (lod_index, lod) in summary.lods.iter().enumerate() {
+                println!(
+                    "LOD {}: vertices {}, triangles {}",
+                    lod_index,
+                    lod.vertices
+                        .map_or("?".to_string(), |count| count.to_string()),
+                    lod.triangles
+                        .map_or("?".to_string(), |count| count.to_string())
+                );
+            }
+            for slot in &summary.material_slots {
+                println!("Material {}: {}", slot.slot_name, slot.material);
+            }
+            if let Some(bounds) = &summary.bounds {
+                println!(
+                    "Bounds: origin {:?}, extent {:?}, radius {}",
+                    bounds.origin, bounds.box_extent, bounds.sphere_radius
+                );
+            }
+            println!("Nanite: {}", summary.nanite_enabled);
+            println!("Sockets: {}", summary.socket_names.join(", "));
+        }
+    }
+
+    if options.show_kismet {
+        for export_index in parser.find_exports_by_class("Function")? {
+            let function = parser.disassemble_function(export_index)?;
+            if !function.statements.is_empty() {
+                println!("\n{}", function.listing());
+            }
+        }
+    }
+
+    if options.show_schema {
+        for schema in parser.read_struct_schemas()? {
+            println!("\n{}\n", schema.path);
+            println!("{}", schema.declaration());
+        }
+    }
+
+    if options.show_enums {
+        for definition in parser.get_enums()? {
+            println!("\nEnum {} ({:?})\n", definition.path, definition.cpp_form);
+            for entry in &definition.entries {
+                match &entry.display_name {
+                    Some(display_name) => {
+                        println!("{} = {} \"{}\"", entry.name, entry.value, display_name)
+                    }
+                    None => println!("{} = {}", entry.name, entry.value),
+                }
+            }
+        }
+    }
+
+    if options.show_sounds {
+        for export_index in parser.find_exports_by_class("SoundWave")? {
+            let wave = parser.read_sound_wave(export_index)?;
+            println!(
+                "\nSoundWave {}\n",
+                parser.get_object_path(export_index as i32 + 1)?
+            );
+            println!("Duration          : {:?}", wave.duration);
+            println!("SampleRate        : {:?}", wave.sample_rate);
+            println!("NumChannels       : {:?}", wave.num_channels);
+            println!("CompressionQuality: {}", wave.compression_quality);
+            println!("CompressionType   : {:?}", wave.compression_type);
+            println!("Streaming         : {}", wave.streaming);
+            println!("SeekableStreaming : {}", wave.seekable_streaming);
+            println!("LoadingBehavior   : {}", wave.loading_behavior);
+            println!("Looping           : {}", wave.looping);
+        }
+
+        for export_index in parser.find_exports_by_class("SoundCue")? {
+            let cue = parser.read_sound_cue(export_index)?;
+            println!(
+                "\nSoundCue {}\n",
+                parser.get_object_path(export_index as i32 + 1)?
+            );
+            for node in &cue.nodes {
+                let first = if node.index == cue.first_node {
+                    " (output)"
+                } else {
+                    ""
+                };
+                println!("Node {} {}{}", node.name, node.class_name, first);
+                if let Some(sound_wave) = &node.sound_wave {
+                    println!("  Wave: {}", sound_wave);
+                }
+            }
+            for (parent, child) in cue.connections() {
+                println!(
+                    "{} -> {}",
+                    parser.get_object_name(parent)?,
+                    parser.get_object_name(child)?
+                );
+            }
+        }
+    }
+
+    if options.show_materials {
+        let mut materials = parser.find_exports_by_class("Material")?;
+        materials.extend(parser.find_exports_by_class("MaterialInstanceConstant")?);
+        for export_index in materials {
+            let material = parser.read_material(export_index)?;
+            println!(
+                "\n{:?} {}\n",
+                material.kind,
+                parser.get_object_path(export_index as i32 + 1)?
+            );
+            if let Some(parent) = &material.parent {
+                println!("Parent      : {}", parent);
+            }
+            println!("BlendMode   : {:?}", material.blend_mode);
+            println!("ShadingModel: {:?}", material.shading_model);
+            println!("TwoSided    : {:?}", material.two_sided);
+            for parameter in &material.parameters {
+                println!("Parameter {}: {:?}", parameter.name, parameter.value);
+            }
+            for texture in &material.textures {
+                println!("Texture {}", texture);
+            }
+        }
+    }
+
+    if options.show_world && !parser.find_exports_by_class("World")?.is_empty() {
+        let world = parser.read_world()?;
+        println!("\nWorld {}\n", world.world);
+        for actor in &world.actors {
+            println!("Actor {} ({})", actor.display_name(), actor.class_name);
+            if let Some(folder_path) = &actor.folder_path {
+                println!("  Folder   : {}", folder_path);
+            }
+            if let Some(transform) = &actor.transform {
+                println!("  Location : {:?}", transform.location);
+                println!("  Rotation : {:?}", transform.rotation);
+                println!("  Scale    : {:?}", transform.scale);
+            }
+        }
+        for level in &world.streaming_levels {
+            println!(
+                "StreamingLevel {} ({}, loaded {}, visible {})",
+                level.world_asset,
+                level.class_name,
+                level.initially_loaded,
+                level.initially_visible
+            );
+        }
+        if let Some(class_name) = &world.world_settings_class {
+            println!("WorldSettings ({})", class_name);
+            for property in &world.world_settings {
+                println!(
+                    "  {}: {}",
+                    property.display_name(),
+                    property.value.export_text()
+                );
+            }
+        }
+    }
+
+    if let (Some(content_root), Some(package_path)) =
+        (&options.external_actors_content_root, &options.package_path)
+    {
+        let packages = read_external_packages(package_path, content_root)?;
+        for external in &packages.actors {
+            println!(
+                "\nExternalActor {} ({})\n",
+                external.actor.display_name(),
+                external.actor.class_name
+            );
+            println!("Package          : {}", external.package_path.display());
+            println!("Level            : {}", external.level);
+            println!("DataLayers       : {}", external.data_layers.join(", "));
+            println!("RuntimeGrid      : {:?}", external.runtime_grid);
+            println!("IsSpatiallyLoaded: {}", external.is_spatially_loaded);
+            if let Some(grid_placement) = &external.grid_placement {
+                println!("GridPlacement    : {}", grid_placement);
+            }
+        }
+        for object in &packages.objects {
+            println!("\nExternalObject {} ({})", object.path, object.class_name);
+        }
+        for (path, error) in &packages.errors {
+            println!("\nFailed to read {}: {}", path.display(), error);
+        }
+    }
+
+    if options.show_animations {
+        let mut animations = parser.find_exports_by_class("AnimSequence")?;
+        animations.extend(parser.find_exports_by_class("AnimMontage")?);
+        for export_index in animations {
+            let animation = parser.read_animation(export_index)?;
+            println!(
+                "\n{:?} {}\n",
+                animation.kind,
+                parser.get_object_path(export_index as i32 + 1)?
+            );
+            println!("Skeleton  : {:?}", animation.skeleton);
+            println!("Frames    : {:?}", animation.frame_count);
+            println!("PlayLength: {}", animation.play_length);
+            println!("SampleRate: {:?}", animation.sample_rate);
+            println!("RateScale : {}", animation.rate_scale);
+            for notify in &animation.notifies {
+                println!(
+                    "Notify {} at {} (track {})",
+                    notify.name,
+                    notify.trigger_time,
+                    notify
+                        .track_name
+                        .clone()
+                        .unwrap_or_else(|| notify.track_index.to_string())
+                );
+            }
+            for curve in &animation.curves {
+                let keys = curve.curve.as_ref().map_or(0, |c| c.points().len());
+                println!("Curve {} ({} keys)", curve.name, keys);
+            }
+            for section in &animation.sections {
+                println!("Section {} at {}", section.name, section.start_time);
+            }
+            for slot in &animation.slots {
+                println!("Slot {}: {}", slot.slot_name, slot.animations.join(", "));
+            }
+        }
+    }
+
+    if options.show_niagara {
+        for export_index in parser.find_exports_by_class("NiagaraSystem")? {
+            let system = parser.read_niagara_system(export_index)?;
+            println!(
+                "\nNiagaraSystem {}\n",
+                parser.get_object_path(export_index as i32 + 1)?
+            );
+            for emitter in &system.emitters {
+                println!(
+                    "Emitter {} ({:?}, enabled {})",
+                    emitter.name, emitter.sim_target, emitter.enabled
+                );
+                for renderer in &emitter.renderers {
+                    println!(
+                        "  Renderer {} (enabled {})",
+                        renderer.class_name, renderer.enabled
+                    );
+                    for material in &renderer.materials {
+                        println!("    Material {}", material);
+                    }
+                    for mesh in &renderer.meshes {
+                        println!("    Mesh {}", mesh);
+                    }
+                }
+            }
+            for parameter in &system.user_parameters {
+                println!("UserParameter {}", parameter);
+            }
+        }
+    }
+
+    if options.show_redirectors {
+        println!("\nRedirectorPackage: {}", parser.is_redirector_package()?);
+        for (source, destination) in parser.get_redirectors()? {
+            println!(
+                "Redirector {} -> {}",
+                source,
+                destination.as_deref().unwrap_or("None")
+            );
+        }
+    }
+
+    if let Some(content_root) = &options.redirector_content_root {
+        let resolver = RedirectorResolver::scan(content_root)?;
+        println!("\nRedirectors: {}", resolver.redirects.len());
+        for broken in resolver.broken_redirectors() {
+            match broken {
+                Redirection::Cycle(chain) => println!("Cycle: {}", chain.join(" -> ")),
+                Redirection::Dangling(chain) => println!("Dangling: {}", chain.join(" -> ")),
+                _ => {}
+            }
+        }
+        for (path, error) in &resolver.errors {
+            println!("Failed to read {}: {}", path.display(), error);
+        }
+    }
+
+    if options.show_data_assets {
+        for export_index in parser.find_data_assets()? {
+            let asset = parser.read_data_asset(export_index)?;
+            println!(
+                "\nDataAsset {} ({}, {:?})\n",
+                asset.path, asset.class_name, asset.kind
+            );
+            if let Some(id) = &asset.primary_asset_id {
+                println!("PrimaryAssetId {}", id);
+            }
+            for bundle in &asset.bundles {
+                println!("Bundle {}", bundle.name);
+                for path in &bundle.assets {
+                    println!("  {}", path);
+                }
+            }
+            for path in &asset.soft_references {
+                println!("SoftReference {}", path);
+            }
+            for property in &asset.properties {
+                println!("{} = {}", property.name, property.value.export_text());
+            }
+        }
+    }
+
+    let exports = parser.get_exports()?;
     for export in exports {
         println!("Export: {export:?}");
     }
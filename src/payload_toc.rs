@@ -0,0 +1,81 @@
+/// EPayloadAccessMode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadAccessMode {
+    /// Stored in the package trailer of this package.
+    Local,
+    /// Stored in the trailer of another package.
+    Referenced,
+    /// Pushed to the virtualization backend and not present on disk.
+    Virtualized,
+    Unknown(u8),
+}
+
+impl From<u8> for PayloadAccessMode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => PayloadAccessMode::Local,
+            1 => PayloadAccessMode::Referenced,
+            2 => PayloadAccessMode::Virtualized,
+            other => PayloadAccessMode::Unknown(other),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PayloadTocEntry {
+    /// FIoHash of the payload.
+    pub identifier: [u8; 20],
+    /// Offset relative to the end of the trailer header.
+    pub offset_in_file: i64,
+    pub compressed_size: u64,
+    pub raw_size: u64,
+    pub flags: u16,
+    pub filter_flags: u16,
+    pub access_mode: PayloadAccessMode,
+}
+
+impl PayloadTocEntry {
+    pub fn is_virtualized(&self) -> bool {
+        self.access_mode == PayloadAccessMode::Virtualized
+    }
+
+    pub fn identifier_hex(&self) -> String {
+        self.identifier
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PackageTrailer {
+    pub trailer_offset: i64,
+    pub version: i32,
+    pub header_length: u32,
+    pub payloads_data_length: u64,
+    pub entries: Vec<PayloadTocEntry>,
+}
+
+impl PackageTrailer {
+    pub fn virtualized_payloads(&self) -> impl Iterator<Item = &PayloadTocEntry> {
+        self.entries.iter().filter(|entry| entry.is_virtualized())
+    }
+
+    pub fn local_payloads(&self) -> impl Iterator<Item = &PayloadTocEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.access_mode == PayloadAccessMode::Local)
+    }
+
+    /// Total raw size of the payloads living in the virtualization backend.
+    pub fn virtualized_raw_size(&self) -> u64 {
+        self.virtualized_payloads()
+            .map(|entry| entry.raw_size)
+            .sum()
+    }
+
+    /// Total raw size of the payloads stored in this package.
+    pub fn local_raw_size(&self) -> u64 {
+        self.local_payloads().map(|entry| entry.raw_size).sum()
+    }
+}
@@ -0,0 +1,359 @@
+//! Conversion of texture pixel data to 8-bit RGBA for image export.
+//! Covers uncompressed formats and the BC1-BC5 block compressed formats.
+
+/// Decodes pixels in the given `EPixelFormat` (e.g. `PF_DXT5`) to 8-bit
+/// RGBA. Returns `None` for unsupported formats or truncated data.
+pub fn decode_to_rgba(pixel_format: &str, width: u32, height: u32, data: &[u8]) -> Option<Vec<u8>> {
+    let (width, height) = (width as usize, height as usize);
+    let pixels = width.checked_mul(height)?;
+
+    let rgba = match pixel_format {
+        "PF_B8G8R8A8" => convert(data, pixels, 4, |p| [p[2], p[1], p[0], p[3]])?,
+        "PF_R8G8B8A8" => convert(data, pixels, 4, |p| [p[0], p[1], p[2], p[3]])?,
+        "PF_G8" | "PF_L8" => convert(data, pixels, 1, |p| [p[0], p[0], p[0], 255])?,
+        "PF_A8" => convert(data, pixels, 1, |p| [255, 255, 255, p[0]])?,
+        "PF_R8G8" => convert(data, pixels, 2, |p| [p[0], p[1], 0, 255])?,
+        "PF_G16" => convert(data, pixels, 2, |p| [p[1], p[1], p[1], 255])?,
+        "PF_R16F" => convert(data, pixels, 2, |p| {
+            let v = half_to_u8([p[0], p[1]]);
+            [v, v, v, 255]
+        })?,
+        "PF_FloatRGBA" => convert(data, pixels, 8, |p| {
+            [
+                half_to_u8([p[0], p[1]]),
+                half_to_u8([p[2], p[3]]),
+                half_to_u8([p[4], p[5]]),
+                half_to_u8([p[6], p[7]]),
+            ]
+        })?,
+        "PF_DXT1" => decode_blocks(data, width, height, 8, |block, out| {
+            decode_bc1_color(block, out, true);
+        })?,
+        "PF_DXT3" => decode_blocks(data, width, height, 16, |block, out| {
+            decode_bc1_color(&block[8..], out, false);
+            for (i, pixel) in out.iter_mut().enumerate() {
+                let nibble = (block[i / 2] >> ((i % 2) * 4)) & 0x0F;
+                pixel[3] = nibble * 17;
+            }
+        })?,
+        "PF_DXT5" => decode_blocks(data, width, height, 16, |block, out| {
+            decode_bc1_color(&block[8..], out, false);
+            let alpha = decode_bc4_channel(&block[..8]);
+            for (pixel, a) in out.iter_mut().zip(alpha) {
+                pixel[3] = a;
+            }
+        })?,
+        "PF_BC4" => decode_blocks(data, width, height, 8, |block, out| {
+            for (pixel, v) in out.iter_mut().zip(decode_bc4_channel(block)) {
+                *pixel = [v, v, v, 255];
+            }
+        })?,
+        "PF_BC5" => decode_blocks(data, width, height, 16, |block, out| {
+            let red = decode_bc4_channel(&block[..8]);
+            let green = decode_bc4_channel(&block[8..]);
+            for ((pixel, r), g) in out.iter_mut().zip(red).zip(green) {
+                // Reconstruct Z of the tangent space normal
+                let x = r as f32 / 127.5 - 1.0;
+                let y = g as f32 / 127.5 - 1.0;
+                let z = (1.0 - x * x - y * y).max(0.0).sqrt();
+                *pixel = [r, g, ((z + 1.0) * 127.5) as u8, 255];
+            }
+        })?,
+        _ => return None,
+    };
+
+    Some(rgba)
+}
+
+/// Decodes `ETextureSourceFormat` source pixels (e.g. `TSF_BGRA8`) to 8-bit RGBA.
+pub fn decode_source_to_rgba(
+    source_format: &str,
+    width: u32,
+    height: u32,
+    data: &[u8],
+) -> Option<Vec<u8>> {
+    let pixels = (width as usize).checked_mul(height as usize)?;
+    match source_format {
+        "TSF_G8" => decode_to_rgba("PF_G8", width, height, data),
+        "TSF_BGRA8" | "TSF_BGRE8" => decode_to_rgba("PF_B8G8R8A8", width, height, data),
+        "TSF_RGBA16F" => decode_to_rgba("PF_FloatRGBA", width, height, data),
+        "TSF_R16F" => decode_to_rgba("PF_R16F", width, height, data),
+        "TSF_G16" => decode_to_rgba("PF_G16", width, height, data),
+        "TSF_RGBA16" => convert(data, pixels, 8, |p| [p[1], p[3], p[5], p[7]]),
+        _ => None,
+    }
+}
+
+fn convert(
+    data: &[u8],
+    pixels: usize,
+    bytes_per_pixel: usize,
+    to_rgba: impl Fn(&[u8]) -> [u8; 4],
+) -> Option<Vec<u8>> {
+    let data = data.get(..pixels.checked_mul(bytes_per_pixel)?)?;
+    Some(
+        data.chunks_exact(bytes_per_pixel)
+            .flat_map(to_rgba)
+            .collect(),
+    )
+}
+
+/// Decodes 4x4 blocks, cropping the edge blocks of sizes that are not a
+/// multiple of four.
+fn decode_blocks(
+    data: &[u8],
+    width: usize,
+    height: usize,
+    block_size: usize,
+    decode_block: impl Fn(&[u8], &mut [[u8; 4]; 16]),
+) -> Option<Vec<u8>> {
+    let blocks_x = width.div_ceil(4);
+    let blocks_y = height.div_ceil(4);
+    let data_size = blocks_x.checked_mul(blocks_y)?.checked_mul(block_size)?;
+    if data.len() < data_size {
+        return None;
+    }
+
+    let mut rgba = vec![0u8; width.checked_mul(height)?.checked_mul(4)?];
+    let mut block_pixels = [[0u8; 4]; 16];
+
+    for by in 0..blocks_y {
+        for bx in 0..blocks_x {
+            let offset = (by * blocks_x + bx) * block_size;
+            decode_block(&data[offset..offset + block_size], &mut block_pixels);
+
+            for (i, pixel) in block_pixels.iter().enumerate() {
+                let x = bx * 4 + i % 4;
+                let y = by * 4 + i / 4;
+                if x < width && y < height {
+                    let out = (y * width + x) * 4;
+                    rgba[out..out + 4].copy_from_slice(pixel);
+                }
+            }
+        }
+    }
+
+    Some(rgba)
+}
+
+/// Decodes the BC1 color part of a block. BC2/BC3 always use four colors.
+fn decode_bc1_color(block: &[u8], out: &mut [[u8; 4]; 16], allow_transparent: bool) {
+    let c0 = u16::from_le_bytes([block[0], block[1]]);
+    let c1 = u16::from_le_bytes([block[2], block[3]]);
+    let indices = u32::from_le_bytes([block[4], block[5], block[6], block[7]]);
+
+    let (r0, g0, b0) = rgb565(c0);
+    let (r1, g1, b1) = rgb565(c1);
+    let mix = |a: u8, b: u8, wa: u32, wb: u32| ((a as u32 * wa + b as u32 * wb) / (wa + wb)) as u8;
+
+    let mut colors = [[r0, g0, b0, 255], [r1, g1, b1, 255], [0; 4], [0; 4]];
+    if c0 > c1 || !allow_transparent {
+        colors[2] = [mix(r0, r1, 2, 1), mix(g0, g1, 2, 1), mix(b0, b1, 2, 1), 255];
+        colors[3] = [mix(r0, r1, 1, 2), mix(g0, g1, 1, 2), mix(b0, b1, 1, 2), 255];
+    } else {
+        colors[2] = [mix(r0, r1, 1, 1), mix(g0, g1, 1, 1), mix(b0, b1, 1, 1), 255];
+        colors[3] = [0, 0, 0, 0];
+    }
+
+    for (i, pixel) in out.iter_mut().enumerate() {
+        *pixel = colors[((indices >> (i * 2)) & 0x3) as usize];
+    }
+}
+
+/// Decodes a BC4 (or BC3 alpha) block into 16 channel values.
+fn decode_bc4_channel(block: &[u8]) -> [u8; 16] {
+    let a0 = block[0] as u32;
+    let a1 = block[1] as u32;
+
+    let mut palette = [0u32; 8];
+    palette[0] = a0;
+    palette[1] = a1;
+    if a0 > a1 {
+        for i in 1..7 {
+            palette[i + 1] = ((7 - i) as u32 * a0 + i as u32 * a1) / 7;
+        }
+    } else {
+        for i in 1..5 {
+            palette[i + 1] = ((5 - i) as u32 * a0 + i as u32 * a1) / 5;
+        }
+        palette[6] = 0;
+        palette[7] = 255;
+    }
+
+    let mut bits = 0u64;
+    for (i, byte) in block[2..8].iter().enumerate() {
+        bits |= (*byte as u64) << (i * 8);
+    }
+
+    let mut values = [0u8; 16];
+    for (i, value) in values.iter_mut().enumerate() {
+        *value = palette[((bits >> (i * 3)) & 0x7) as usize] as u8;
+    }
+    values
+}
+
+fn rgb565(color: u16) -> (u8, u8, u8) {
+    let r = ((color >> 11) & 0x1F) as u32;
+    let g = ((color >> 5) & 0x3F) as u32;
+    let b = (color & 0x1F) as u32;
+    (
+        ((r * 255 + 15) / 31) as u8,
+        ((g * 255 + 31) / 63) as u8,
+        ((b * 255 + 15) / 31) as u8,
+    )
+}
+
+/// Converts an IEEE half float to an 8-bit value, clamped to [0, 1].
+fn half_to_u8(bytes: [u8; 2]) -> u8 {
+    let half = u16::from_le_bytes(bytes);
+    let sign = if half & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((half >> 10) & 0x1F) as i32;
+    let mantissa = (half & 0x3FF) as f32;
+
+    let value = match exponent {
+        0 => sign * mantissa * 2f32.powi(-24),
+        31 => sign * f32::INFINITY,
+        _ => sign * (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+    };
+    (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+
+    /// Palette indices 0, 1, ..., 7 for pixels 0-7 and again for 8-15.
+    const BC4_RAMP_INDICES: [u8; 6] = [0x88, 0xC6, 0xFA, 0x88, 0xC6, 0xFA];
+
+    fn bc4_block(a0: u8, a1: u8) -> [u8; 8] {
+        let mut block = [a0, a1, 0, 0, 0, 0, 0, 0];
+        block[2..].copy_from_slice(&BC4_RAMP_INDICES);
+        block
+    }
+
+    fn pixel(rgba: &[u8], index: usize) -> [u8; 4] {
+        rgba[index * 4..index * 4 + 4].try_into().unwrap()
+    }
+
+    #[test]
+    fn bc1_four_color_block() {
+        // c0 = red > c1 = blue; first row uses indices 0, 1, 2, 3
+        let block = [0x00, 0xF8, 0x1F, 0x00, 0xE4, 0x00, 0x00, 0x00];
+        let rgba = decode_to_rgba("PF_DXT1", 4, 4, &block).unwrap();
+
+        assert_eq!(pixel(&rgba, 0), RED);
+        assert_eq!(pixel(&rgba, 1), BLUE);
+        assert_eq!(pixel(&rgba, 2), [170, 0, 85, 255]);
+        assert_eq!(pixel(&rgba, 3), [85, 0, 170, 255]);
+        assert!((4..16).all(|i| pixel(&rgba, i) == RED));
+    }
+
+    #[test]
+    fn bc1_three_color_block_with_transparency() {
+        // c0 = blue <= c1 = red selects the 3 color + transparent mode
+        let block = [0x1F, 0x00, 0x00, 0xF8, 0xE4, 0x00, 0x00, 0x00];
+        let rgba = decode_to_rgba("PF_DXT1", 4, 4, &block).unwrap();
+
+        assert_eq!(pixel(&rgba, 0), BLUE);
+        assert_eq!(pixel(&rgba, 1), RED);
+        assert_eq!(pixel(&rgba, 2), [127, 0, 127, 255]);
+        assert_eq!(pixel(&rgba, 3), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn bc1_color_of_bc3_ignores_transparent_mode() {
+        let block = [0x1F, 0x00, 0x00, 0xF8, 0xE4, 0x00, 0x00, 0x00];
+        let mut out = [[0u8; 4]; 16];
+        decode_bc1_color(&block, &mut out, false);
+
+        assert_eq!(out[2], [85, 0, 170, 255]);
+        assert_eq!(out[3], [170, 0, 85, 255]);
+    }
+
+    #[test]
+    fn bc4_eight_value_palette() {
+        let values = decode_bc4_channel(&bc4_block(255, 0));
+        let palette = [255, 0, 218, 182, 145, 109, 72, 36];
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(*value, palette[i % 8], "pixel {i}");
+        }
+    }
+
+    #[test]
+    fn bc4_six_value_palette() {
+        let values = decode_bc4_channel(&bc4_block(0, 255));
+        let palette = [0, 255, 51, 102, 153, 204, 0, 255];
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(*value, palette[i % 8], "pixel {i}");
+        }
+    }
+
+    #[test]
+    fn bc3_alpha_block() {
+        let mut block = [0u8; 16];
+        block[..8].copy_from_slice(&bc4_block(255, 0));
+        block[8..].copy_from_slice(&[0x00, 0xF8, 0x00, 0xF8, 0x00, 0x00, 0x00, 0x00]);
+        let rgba = decode_to_rgba("PF_DXT5", 4, 4, &block).unwrap();
+
+        let palette = [255, 0, 218, 182, 145, 109, 72, 36];
+        for i in 0..16 {
+            assert_eq!(pixel(&rgba, i), [255, 0, 0, palette[i % 8]], "pixel {i}");
+        }
+    }
+
+    #[test]
+    fn bc5_reconstructs_normal_z() {
+        // Flat normal: X = Y = 0 gives Z = 1
+        let mut block = [0u8; 16];
+        block[0] = 128;
+        block[8] = 128;
+        let rgba = decode_to_rgba("PF_BC5", 4, 4, &block).unwrap();
+        assert!((0..16).all(|i| pixel(&rgba, i) == [128, 128, 254, 255]));
+
+        // X = 1 leaves no room for Z
+        block[0] = 255;
+        let rgba = decode_to_rgba("PF_BC5", 4, 4, &block).unwrap();
+        assert_eq!(pixel(&rgba, 0), [255, 128, 127, 255]);
+    }
+
+    #[test]
+    fn blocks_are_cropped_to_the_image_size() {
+        let block = [0x00, 0xF8, 0x1F, 0x00, 0xE4, 0x00, 0x00, 0x00];
+        let rgba = decode_to_rgba("PF_DXT1", 2, 1, &block).unwrap();
+        assert_eq!(rgba, [RED, BLUE].concat());
+        assert!(decode_to_rgba("PF_DXT1", 8, 8, &block).is_none());
+    }
+
+    #[test]
+    fn oversized_dimensions_are_rejected() {
+        assert!(decode_to_rgba("PF_DXT5", u32::MAX, u32::MAX, &[0; 16]).is_none());
+        assert!(decode_to_rgba("PF_B8G8R8A8", u32::MAX, u32::MAX, &[0; 4]).is_none());
+        assert!(decode_source_to_rgba("TSF_RGBA16", u32::MAX, u32::MAX, &[0; 8]).is_none());
+    }
+
+    #[test]
+    fn half_float_conversion() {
+        let convert = |half: u16| half_to_u8(half.to_le_bytes());
+
+        assert_eq!(convert(0x0000), 0);
+        assert_eq!(convert(0x3C00), 255); // 1.0
+        assert_eq!(convert(0x3800), 128); // 0.5
+        assert_eq!(convert(0x4000), 255); // 2.0 is clamped
+        assert_eq!(convert(0x1C00), 1); // 2^-8 rounds up to 1/255
+        assert_eq!(convert(0x03FF), 0); // largest subnormal
+        assert_eq!(convert(0x0001), 0); // smallest subnormal
+        assert_eq!(convert(0x7C00), 255); // +inf
+        assert_eq!(convert(0xFC00), 0); // -inf
+        assert_eq!(convert(0xBC00), 0); // -1.0
+        assert_eq!(convert(0x8000), 0); // -0.0
+    }
+
+    #[test]
+    fn r16f_expands_to_grey() {
+        let rgba = decode_to_rgba("PF_R16F", 1, 1, &0x3800u16.to_le_bytes()).unwrap();
+        assert_eq!(rgba, [128, 128, 128, 255]);
+    }
+}
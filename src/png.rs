@@ -0,0 +1,183 @@
+//! Minimal PNG encoder used to export raw pixel data without pulling in an
+//! image crate. Image data is written as uncompressed (stored) deflate blocks.
+
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+const MAX_STORED_BLOCK: usize = 0xFFFF;
+
+/// Encodes 8-bit RGBA pixels as a PNG file.
+pub fn encode_rgba(width: u32, height: u32, rgba: &[u8]) -> Vec<u8> {
+    let row_size = width as usize * 4;
+
+    // Each scanline is prefixed with filter type 0 (None)
+    let mut raw = Vec::with_capacity((row_size + 1) * height as usize);
+    for row in rgba.chunks(row_size).take(height as usize) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    header.extend_from_slice(&[8, 6, 0, 0, 0]); // 8-bit, RGBA, deflate, no filter, no interlace
+
+    let mut png = Vec::with_capacity(raw.len() + 128);
+    png.extend_from_slice(&PNG_SIGNATURE);
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// Encodes 8-bit BGRA pixels, as used by Unreal's FColor, as a PNG file.
+pub fn encode_bgra(width: u32, height: u32, bgra: &[u8]) -> Vec<u8> {
+    let rgba: Vec<u8> = bgra
+        .chunks_exact(4)
+        .flat_map(|px| [px[2], px[1], px[0], px[3]])
+        .collect();
+    encode_rgba(width, height, &rgba)
+}
+
+fn write_chunk(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(chunk_type);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + data.len() / MAX_STORED_BLOCK * 5 + 16);
+    out.extend_from_slice(&[0x78, 0x01]);
+
+    let mut blocks = data.chunks(MAX_STORED_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend_from_slice(&[0x01, 0x00, 0x00, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_final = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(is_final as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD_ADLER: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MOD_ADLER;
+        b %= MOD_ADLER;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Splits a PNG file into (chunk type, data) pairs, checking each CRC.
+    fn read_chunks(png: &[u8]) -> Vec<([u8; 4], Vec<u8>)> {
+        assert_eq!(png[..8], PNG_SIGNATURE);
+        let mut chunks = Vec::new();
+        let mut rest = &png[8..];
+        while !rest.is_empty() {
+            let len = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let chunk_type: [u8; 4] = rest[4..8].try_into().unwrap();
+            let data = rest[8..8 + len].to_vec();
+            let crc = u32::from_be_bytes(rest[8 + len..12 + len].try_into().unwrap());
+            assert_eq!(crc, crc32(&rest[4..8 + len]), "CRC of {chunk_type:?}");
+            chunks.push((chunk_type, data));
+            rest = &rest[12 + len..];
+        }
+        chunks
+    }
+
+    /// Inflates a zlib stream made of stored blocks, checking the Adler-32.
+    fn inflate_stored(zlib: &[u8]) -> Vec<u8> {
+        assert_eq!(zlib[..2], [0x78, 0x01]);
+        let mut data = Vec::new();
+        let mut pos = 2;
+        loop {
+            let is_final = zlib[pos] & 1 != 0;
+            let len = u16::from_le_bytes([zlib[pos + 1], zlib[pos + 2]]);
+            let nlen = u16::from_le_bytes([zlib[pos + 3], zlib[pos + 4]]);
+            assert_eq!(len, !nlen);
+            pos += 5;
+            data.extend_from_slice(&zlib[pos..pos + len as usize]);
+            pos += len as usize;
+            if is_final {
+                break;
+            }
+        }
+        let adler = u32::from_be_bytes(zlib[pos..pos + 4].try_into().unwrap());
+        assert_eq!(adler, adler32(&data));
+        assert_eq!(pos + 4, zlib.len());
+        data
+    }
+
+    #[test]
+    fn checksums_match_reference_values() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+        assert_eq!(adler32(b""), 1);
+        // Long enough to need the deferred modulo
+        assert_eq!(adler32(&[0xFF; 6000]), 0xA497_59EA);
+    }
+
+    #[test]
+    fn round_trip_across_stored_blocks() {
+        // 200 x 100 RGBA pixels don't fit in one stored block
+        let (width, height) = (200u32, 100u32);
+        let rgba: Vec<u8> = (0..width * height * 4).map(|i| (i % 251) as u8).collect();
+        let png = encode_rgba(width, height, &rgba);
+
+        let chunks = read_chunks(&png);
+        let types: Vec<&[u8; 4]> = chunks.iter().map(|(chunk_type, _)| chunk_type).collect();
+        assert_eq!(types, [b"IHDR", b"IDAT", b"IEND"]);
+
+        let header = &chunks[0].1;
+        assert_eq!(header[..4], width.to_be_bytes());
+        assert_eq!(header[4..8], height.to_be_bytes());
+        assert_eq!(header[8..], [8, 6, 0, 0, 0]);
+
+        let raw = inflate_stored(&chunks[1].1);
+        let row_size = width as usize * 4;
+        assert_eq!(raw.len(), (row_size + 1) * height as usize);
+        let mut decoded = Vec::with_capacity(rgba.len());
+        for row in raw.chunks(row_size + 1) {
+            assert_eq!(row[0], 0);
+            decoded.extend_from_slice(&row[1..]);
+        }
+        assert_eq!(decoded, rgba);
+    }
+
+    #[test]
+    fn bgra_is_swizzled_to_rgba() {
+        let png = encode_bgra(1, 1, &[1, 2, 3, 4]);
+        let raw = inflate_stored(&read_chunks(&png)[1].1);
+        assert_eq!(raw, [0, 3, 2, 1, 4]);
+    }
+}
@@ -1,9 +1,65 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{Read, Seek, SeekFrom};
+
+use crate::curve::{RichCurveInterpMode, RichCurveTangentMode, RichCurveTangentWeightMode};
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::property_value::{Property, PropertyValue};
+use crate::reader::UassetReader;
+use crate::tag_value::TextValue;
 use crate::unreal_types::FName;
+use crate::versions::EUnrealEngineObjectUE5Version;
+
+const PKG_UNVERSIONED_PROPERTIES: u32 = 0x0000_2000;
+const RF_CLASS_DEFAULT_OBJECT: i32 = 0x0000_0010;
+
+// EPropertyTagFlags
+const TAG_HAS_ARRAY_INDEX: u8 = 0x01;
+const TAG_HAS_PROPERTY_GUID: u8 = 0x02;
+const TAG_HAS_PROPERTY_EXTENSIONS: u8 = 0x04;
+const TAG_HAS_BINARY_OR_NATIVE_SERIALIZE: u8 = 0x08;
+const TAG_BOOL_TRUE: u8 = 0x10;
+
+// EPropertyTagExtension / EClassSerializationControlExtension
+const EXTENSION_OVERRIDABLE_INFORMATION: u8 = 0x02;
+const CONTROL_OVERRIDABLE_SERIALIZATION_INFORMATION: u8 = 0x02;
+
+/// Full type of a property, e.g. `MapProperty(NameProperty, StructProperty(Vector))`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PropertyTypeName {
+    pub name: String,
+    pub parameters: Vec<PropertyTypeName>,
+}
+
+impl PropertyTypeName {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            parameters: Vec::new(),
+        }
+    }
+
+    pub fn parameter(&self, index: usize) -> Option<&PropertyTypeName> {
+        self.parameters.get(index)
+    }
+
+    /// Name of the first parameter, which holds the struct or enum name of
+    /// `StructProperty`, `ByteProperty` and `EnumProperty` types.
+    pub fn parameter_name(&self, index: usize) -> Option<&str> {
+        self.parameters.get(index).map(|p| p.name.as_str())
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct PropertyData {
     pub name: FName,
+    pub property_name: String,
     pub property_type: String,
+    pub type_name: PropertyTypeName,
+    pub array_index: i32,
+    pub property_guid: Option<[u8; 16]>,
+    pub bool_value: bool,
+    pub has_binary_or_native_serialize: bool,
     pub data: Vec<u8>,
 }
 
@@ -11,8 +67,742 @@ impl PropertyData {
     pub fn new(name: FName, property_type: String, data: Vec<u8>) -> Self {
         Self {
             name,
+            property_name: String::new(),
+            type_name: PropertyTypeName::new(&property_type),
             property_type,
+            array_index: 0,
+            property_guid: None,
+            bool_value: false,
+            has_binary_or_native_serialize: false,
             data,
         }
     }
 }
+
+#[derive(Debug, Default)]
+pub struct ObjectProperties {
+    pub properties: Vec<Property>,
+    pub object_guid: Option<[u8; 16]>,
+}
+
+impl ObjectProperties {
+    pub fn find(&self, name: &str) -> Option<&Property> {
+        self.properties
+            .iter()
+            .find(|p| p.name == name || p.display_name() == name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&PropertyValue> {
+        self.find(name).map(|p| &p.value)
+    }
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads the tagged properties of an export (0-based index into the export table).
+    pub fn read_export_properties(&mut self, export_index: usize) -> Result<ObjectProperties> {
+        self.seek_export_properties(export_index)
+    }
+
+    /// Reads the tagged properties of an export and leaves the reader positioned
+    /// at the native data serialized after them.
+    pub(crate) fn seek_export_properties(
+        &mut self,
+        export_index: usize,
+    ) -> Result<ObjectProperties> {
+        if self.summary.package_flags & PKG_UNVERSIONED_PROPERTIES != 0 {
+            return Err(ParseError::UnversionedPropertiesNotSupported);
+        }
+
+        // Load the name and object tables up front so that resolving names
+        // and references doesn't move the reader away from the export data
+        self.load_object_tables()?;
+
+        let (serial_offset, object_flags) = {
+            let exports = self.get_exports()?;
+            let export = exports
+                .get(export_index)
+                .ok_or(ParseError::InvalidPackageIndex(export_index as i32 + 1))?;
+            (export.serial_offset, export.object_flags)
+        };
+
+        self.check_file_offset(serial_offset)?;
+        self.reader.seek(SeekFrom::Start(serial_offset as u64))?;
+
+        if self.summary.file_version_ue5
+            >= EUnrealEngineObjectUE5Version::PropertyTagExtensionAndOverridableSerialization as i32
+        {
+            let control = self.reader.read_u8()?;
+            if control & CONTROL_OVERRIDABLE_SERIALIZATION_INFORMATION != 0 {
+                self.reader.read_u8()?; // EOverriddenPropertyOperation
+            }
+        }
+
+        let properties = self.read_tagged_properties()?;
+
+        let mut object_guid = None;
+        if object_flags & RF_CLASS_DEFAULT_OBJECT == 0
+            && self.reader.read_u32::<LittleEndian>()? != 0
+        {
+            let mut guid = [0u8; 16];
+            self.reader.read_exact(&mut guid)?;
+            object_guid = Some(guid);
+        }
+
+        Ok(ObjectProperties {
+            properties,
+            object_guid,
+        })
+    }
+
+    /// Reads tagged properties up to the terminating `None` tag and decodes
+    /// their values. Values that cannot be decoded are kept as raw bytes.
+    pub(crate) fn read_tagged_properties(&mut self) -> Result<Vec<Property>> {
+        let mut properties = Vec::new();
+        while let Some(tag) = self.read_property_tag()? {
+            let end = self.reader.stream_position()?;
+            let start = end - tag.data.len() as u64;
+
+            self.reader.seek(SeekFrom::Start(start))?;
+            let value = match self.read_tag_value(&tag) {
+                Ok(value) if self.reader.stream_position()? == end => value,
+                _ => PropertyValue::Raw(tag.data.clone()),
+            };
+            self.reader.seek(SeekFrom::Start(end))?;
+
+            properties.push(Property {
+                name: tag.property_name,
+                type_name: tag.type_name,
+                array_index: tag.array_index,
+                value,
+            });
+        }
+        Ok(properties)
+    }
+
+    /// Reads a property tag and its raw value.
+    pub(crate) fn read_property_tag(&mut self) -> Result<Option<PropertyData>> {
+        let Some((mut tag, size)) = self.read_property_tag_header()? else {
+            return Ok(None);
+        };
+
+        let position = self.reader.stream_position()?;
+        self.check_file_offset(position as i64 + size as i64)?;
+
+        tag.data = vec![0u8; size as usize];
+        self.reader.read_exact(&mut tag.data)?;
+        Ok(Some(tag))
+    }
+
+    /// Reads a property tag up to its value, returning the tag and value size.
+    fn read_property_tag_header(&mut self) -> Result<Option<(PropertyData, i32)>> {
+        let name = self.reader.read_fname()?;
+        let property_name = self.get_name_string(&name)?;
+
+        if property_name == "None" {
+            return Ok(None);
+        }
+
+        let mut array_index = 0;
+        let mut property_guid = None;
+        let mut bool_value = false;
+        let mut has_binary_or_native_serialize = false;
+
+        let type_name;
+        let size;
+
+        if self.has_complete_type_names() {
+            type_name = self.read_property_type_name()?;
+            size = self.reader.read_i32::<LittleEndian>()?;
+
+            let flags = self.reader.read_u8()?;
+            if flags & TAG_HAS_ARRAY_INDEX != 0 {
+                array_index = self.reader.read_i32::<LittleEndian>()?;
+            }
+            if flags & TAG_HAS_PROPERTY_GUID != 0 {
+                let mut guid = [0u8; 16];
+                self.reader.read_exact(&mut guid)?;
+                property_guid = Some(guid);
+            }
+            if flags & TAG_HAS_PROPERTY_EXTENSIONS != 0 {
+                self.read_property_extensions()?;
+            }
+            has_binary_or_native_serialize = flags & TAG_HAS_BINARY_OR_NATIVE_SERIALIZE != 0;
+            bool_value = flags & TAG_BOOL_TRUE != 0;
+        } else {
+            let property_type = self.reader.read_fname()?;
+            let property_type = self.get_name_string(&property_type)?;
+            size = self.reader.read_i32::<LittleEndian>()?;
+            array_index = self.reader.read_i32::<LittleEndian>()?;
+
+            let mut parameters = Vec::new();
+            match property_type.as_str() {
+                "StructProperty" => {
+                    let struct_name = self.reader.read_fname()?;
+                    parameters.push(PropertyTypeName::new(&self.get_name_string(&struct_name)?));
+                    self.reader.skip_bytes(16)?; // StructGuid
+                }
+                "BoolProperty" => {
+                    bool_value = self.reader.read_u8()? != 0;
+                }
+                "ByteProperty" | "EnumProperty" => {
+                    let enum_name = self.reader.read_fname()?;
+                    let enum_name = self.get_name_string(&enum_name)?;
+                    if enum_name != "None" {
+                        parameters.push(PropertyTypeName::new(&enum_name));
+                    }
+                }
+                "ArrayProperty" | "SetProperty" | "OptionalProperty" => {
+                    let inner_type = self.reader.read_fname()?;
+                    parameters.push(PropertyTypeName::new(&self.get_name_string(&inner_type)?));
+                }
+                "MapProperty" => {
+                    let key_type = self.reader.read_fname()?;
+                    let value_type = self.reader.read_fname()?;
+                    parameters.push(PropertyTypeName::new(&self.get_name_string(&key_type)?));
+                    parameters.push(PropertyTypeName::new(&self.get_name_string(&value_type)?));
+                }
+                _ => {}
+            }
+
+            if self.reader.read_u8()? != 0 {
+                let mut guid = [0u8; 16];
+                self.reader.read_exact(&mut guid)?;
+                property_guid = Some(guid);
+            }
+
+            if self.summary.file_version_ue5
+                >= EUnrealEngineObjectUE5Version::PropertyTagExtensionAndOverridableSerialization
+                    as i32
+            {
+                self.read_property_extensions()?;
+            }
+
+            type_name = PropertyTypeName {
+                name: property_type,
+                parameters,
+            };
+        }
+
+        if size < 0 {
+            return Err(ParseError::InvalidArraySize(size));
+        }
+
+        let tag = PropertyData {
+            name,
+            property_name,
+            property_type: type_name.name.clone(),
+            type_name,
+            array_index,
+            property_guid,
+            bool_value,
+            has_binary_or_native_serialize,
+            data: Vec::new(),
+        };
+        Ok(Some((tag, size)))
+    }
+
+    fn has_complete_type_names(&self) -> bool {
+        self.summary.file_version_ue5
+            >= EUnrealEngineObjectUE5Version::PropertyTagCompleteTypeName as i32
+    }
+
+    fn read_property_type_name(&mut self) -> Result<PropertyTypeName> {
+        let name = self.reader.read_fname()?;
+        let inner_count = self.reader.read_i32::<LittleEndian>()?;
+
+        if inner_count < 0 {
+            return Err(ParseError::InvalidArraySize(inner_count));
+        }
+
+        let mut type_name = PropertyTypeName::new(&self.get_name_string(&name)?);
+        for _ in 0..inner_count {
+            type_name.parameters.push(self.read_property_type_name()?);
+        }
+        Ok(type_name)
+    }
+
+    fn read_property_extensions(&mut self) -> Result<()> {
+        let extensions = self.reader.read_u8()?;
+        if extensions & EXTENSION_OVERRIDABLE_INFORMATION != 0 {
+            self.reader.read_u8()?; // EOverriddenPropertyOperation
+            self.reader.read_u32::<LittleEndian>()?; // bExperimentalOverridableLogic
+        }
+        Ok(())
+    }
+
+    /// Decodes the value of a top level property tag, whose serialization
+    /// differs from container elements for bools, enums and structs.
+    fn read_tag_value(&mut self, tag: &PropertyData) -> Result<PropertyValue> {
+        match tag.type_name.name.as_str() {
+            "BoolProperty" => Ok(PropertyValue::Bool(tag.bool_value)),
+            "StructProperty" => {
+                let struct_name = tag.type_name.parameter_name(0).unwrap_or_default();
+                let native = if self.has_complete_type_names() {
+                    tag.has_binary_or_native_serialize
+                } else {
+                    is_native_struct(struct_name)
+                };
+                self.read_struct_value(struct_name, native)
+            }
+            "ArrayProperty" | "SetProperty"
+                if !self.has_complete_type_names()
+                    && tag
+                        .type_name
+                        .parameter(0)
+                        .is_some_and(|inner| inner.name == "ByteProperty") =>
+            {
+                // Older tags don't name the enum of byte elements, but enum
+                // bytes are written as enumerator names, which the value size
+                // tells apart from plain bytes
+                let header = if tag.type_name.name == "SetProperty" {
+                    8
+                } else {
+                    4
+                };
+                let start = self.reader.stream_position()?;
+                self.reader.seek(SeekFrom::Start(start + header - 4))?;
+                let count = self.reader.read_i32::<LittleEndian>()?;
+                self.reader.seek(SeekFrom::Start(start))?;
+
+                let mut type_name = tag.type_name.clone();
+                if count > 0 && tag.data.len() as u64 == header + count as u64 * 8 {
+                    type_name.parameters[0]
+                        .parameters
+                        .push(PropertyTypeName::new(""));
+                }
+                self.read_property_value(&type_name)
+            }
+            _ => self.read_property_value(&tag.type_name),
+        }
+    }
+
+    /// Decodes a value as serialized inside a property tag or a container.
+    pub(crate) fn read_property_value(
+        &mut self,
+        type_name: &PropertyTypeName,
+    ) -> Result<PropertyValue> {
+        let value = match type_name.name.as_str() {
+            "BoolProperty" => PropertyValue::Bool(self.reader.read_u8()? != 0),
+            "Int8Property" => PropertyValue::Int8(self.reader.read_i8()?),
+            "Int16Property" => PropertyValue::Int16(self.reader.read_i16::<LittleEndian>()?),
+            "IntProperty" => PropertyValue::Int(self.reader.read_i32::<LittleEndian>()?),
+            "Int64Property" => PropertyValue::Int64(self.reader.read_i64::<LittleEndian>()?),
+            "UInt16Property" => PropertyValue::UInt16(self.reader.read_u16::<LittleEndian>()?),
+            "UInt32Property" => PropertyValue::UInt32(self.reader.read_u32::<LittleEndian>()?),
+            "UInt64Property" => PropertyValue::UInt64(self.reader.read_u64::<LittleEndian>()?),
+            "FloatProperty" => PropertyValue::Float(self.reader.read_f32::<LittleEndian>()?),
+            "DoubleProperty" => PropertyValue::Double(self.reader.read_f64::<LittleEndian>()?),
+            "ByteProperty" => match type_name.parameter_name(0) {
+                Some(enum_name) if enum_name != "None" => {
+                    let value = self.reader.read_fname()?;
+                    PropertyValue::Enum(self.get_name_string(&value)?)
+                }
+                _ => PropertyValue::Byte(self.reader.read_u8()?),
+            },
+            "EnumProperty" => {
+                let value = self.reader.read_fname()?;
+                PropertyValue::Enum(self.get_name_string(&value)?)
+            }
+            "NameProperty" => {
+                let value = self.reader.read_fname()?;
+                PropertyValue::Name(self.get_name_string(&value)?)
+            }
+            "StrProperty" | "VerseStringProperty" => {
+                PropertyValue::Str(self.reader.read_fstring()?)
+            }
+            "TextProperty" => PropertyValue::Text(self.read_text()?),
+            "ObjectProperty" | "ClassProperty" | "WeakObjectProperty" | "InterfaceProperty" => {
+                let index = self.reader.read_i32::<LittleEndian>()?;
+                self.object_reference(index)?
+            }
+            "LazyObjectProperty" => {
+                let mut guid = [0u8; 16];
+                self.reader.read_exact(&mut guid)?;
+                PropertyValue::Str(guid.iter().map(|b| format!("{:02X}", b)).collect())
+            }
+            "SoftObjectProperty" | "SoftClassProperty" => {
+                PropertyValue::SoftObject(self.read_soft_object_path()?)
+            }
+            "StructProperty" => {
+                let struct_name = type_name.parameter_name(0).unwrap_or_default();
+                self.read_struct_value(struct_name, is_native_struct(struct_name))?
+            }
+            "ArrayProperty" => {
+                let count = self.read_element_count()?;
+                let mut inner = type_name.parameter(0).cloned().unwrap_or_default();
+
+                // Older packages write a full tag for struct elements, which
+                // carries the struct name the outer tag lacks
+                if inner.name == "StructProperty"
+                    && !self.has_complete_type_names()
+                    && count > 0
+                    && let Some((inner_tag, _)) = self.read_property_tag_header()?
+                {
+                    inner = inner_tag.type_name;
+                }
+
+                let mut values = Vec::with_capacity(count);
+                for _ in 0..count {
+                    values.push(self.read_property_value(&inner)?);
+                }
+                PropertyValue::Array(values)
+            }
+            "SetProperty" => {
+                let inner = type_name.parameter(0).cloned().unwrap_or_default();
+                let removed = self.read_element_count()?;
+                for _ in 0..removed {
+                    self.read_property_value(&inner)?;
+                }
+
+                let count = self.read_element_count()?;
+                let mut values = Vec::with_capacity(count);
+                for _ in 0..count {
+                    values.push(self.read_property_value(&inner)?);
+                }
+                PropertyValue::Set(values)
+            }
+            "MapProperty" => {
+                let key_type = type_name.parameter(0).cloned().unwrap_or_default();
+                let value_type = type_name.parameter(1).cloned().unwrap_or_default();
+                let removed = self.read_element_count()?;
+                for _ in 0..removed {
+                    self.read_property_value(&key_type)?;
+                }
+
+                let count = self.read_element_count()?;
+                let mut entries = Vec::with_capacity(count);
+                for _ in 0..count {
+                    let key = self.read_property_value(&key_type)?;
+                    let value = self.read_property_value(&value_type)?;
+                    entries.push((key, value));
+                }
+                PropertyValue::Map(entries)
+            }
+            "OptionalProperty" => {
+                let inner = type_name.parameter(0).cloned().unwrap_or_default();
+                if self.reader.read_u8()? != 0 {
+                    PropertyValue::Optional(Some(Box::new(self.read_property_value(&inner)?)))
+                } else {
+                    PropertyValue::Optional(None)
+                }
+            }
+            "DelegateProperty" => {
+                let (object, function) = self.read_script_delegate()?;
+                PropertyValue::Delegate { object, function }
+            }
+            "MulticastDelegateProperty"
+            | "MulticastInlineDelegateProperty"
+            | "MulticastSparseDelegateProperty" => {
+                let count = self.read_element_count()?;
+                let mut delegates = Vec::with_capacity(count);
+                for _ in 0..count {
+                    delegates.push(self.read_script_delegate()?);
+                }
+                PropertyValue::MulticastDelegate(delegates)
+            }
+            "FieldPathProperty" => {
+                let count = self.read_element_count()?;
+                let mut path = Vec::with_capacity(count);
+                for _ in 0..count {
+                    let name = self.reader.read_fname()?;
+                    path.push(self.get_name_string(&name)?);
+                }
+                self.reader.read_i32::<LittleEndian>()?; // ResolvedOwner
+                PropertyValue::FieldPath(path)
+            }
+            other => return Err(ParseError::UnsupportedPropertyType(other.to_string())),
+        };
+        Ok(value)
+    }
+
+    /// Decodes a struct value, either natively serialized or as tagged properties.
+    pub(crate) fn read_struct_value(
+        &mut self,
+        struct_name: &str,
+        native: bool,
+    ) -> Result<PropertyValue> {
+        let properties = if native {
+            self.read_native_struct(struct_name)?
+        } else {
+            self.read_tagged_properties()?
+        };
+        Ok(PropertyValue::Struct {
+            struct_name: struct_name.to_string(),
+            properties,
+        })
+    }
+
+    fn read_native_struct(&mut self, struct_name: &str) -> Result<Vec<Property>> {
+        let large_world_coordinates = self.summary.file_version_ue5
+            >= EUnrealEngineObjectUE5Version::LargeWorldCoordinates as i32;
+        let real = if large_world_coordinates {
+            "DoubleProperty"
+        } else {
+            "FloatProperty"
+        };
+
+        let fields: &[(&str, &str)] = match struct_name {
+            "Vector"
+            | "Vector_NetQuantize"
+            | "Vector_NetQuantize10"
+            | "Vector_NetQuantize100"
+            | "Vector_NetQuantizeNormal" => &[("X", real), ("Y", real), ("Z", real)],
+            "Vector2D" => &[("X", real), ("Y", real)],
+            "Vector4" | "Quat" | "Plane" => &[("X", real), ("Y", real), ("Z", real), ("W", real)],
+            "Rotator" => &[("Pitch", real), ("Yaw", real), ("Roll", real)],
+            "Vector3f" => &[
+                ("X", "FloatProperty"),
+                ("Y", "FloatProperty"),
+                ("Z", "FloatProperty"),
+            ],
+            "Vector2f" => &[("X", "FloatProperty"), ("Y", "FloatProperty")],
+            "Vector4f" => &[
+                ("X", "FloatProperty"),
+                ("Y", "FloatProperty"),
+                ("Z", "FloatProperty"),
+                ("W", "FloatProperty"),
+            ],
+            "LinearColor" => &[
+                ("R", "FloatProperty"),
+                ("G", "FloatProperty"),
+                ("B", "FloatProperty"),
+                ("A", "FloatProperty"),
+            ],
+            "Color" => &[
+                ("B", "ByteProperty"),
+                ("G", "ByteProperty"),
+                ("R", "ByteProperty"),
+                ("A", "ByteProperty"),
+            ],
+            "IntPoint" => &[("X", "IntProperty"), ("Y", "IntProperty")],
+            "IntVector" => &[
+                ("X", "IntProperty"),
+                ("Y", "IntProperty"),
+                ("Z", "IntProperty"),
+            ],
+            "Guid" => &[
+                ("A", "UInt32Property"),
+                ("B", "UInt32Property"),
+                ("C", "UInt32Property"),
+                ("D", "UInt32Property"),
+            ],
+            "DateTime" | "Timespan" => &[("Ticks", "Int64Property")],
+            "FrameNumber" => &[("Value", "IntProperty")],
+            "Box" | "Box2D" => {
+                let vector = if struct_name == "Box" {
+                    "Vector"
+                } else {
+                    "Vector2D"
+                };
+                let min = self.read_native_struct(vector)?;
+                let max = self.read_native_struct(vector)?;
+                let is_valid = self.reader.read_u8()? != 0;
+                return Ok(vec![
+                    native_field("Min", "StructProperty", struct_value(vector, min)),
+                    native_field("Max", "StructProperty", struct_value(vector, max)),
+                    native_field("IsValid", "BoolProperty", PropertyValue::Bool(is_valid)),
+                ]);
+            }
+            "BoxSphereBounds" => {
+                let origin = self.read_native_struct("Vector")?;
+                let box_extent = self.read_native_struct("Vector")?;
+                let sphere_radius = self.read_property_value(&PropertyTypeName::new(real))?;
+                return Ok(vec![
+                    native_field("Origin", "StructProperty", struct_value("Vector", origin)),
+                    native_field(
+                        "BoxExtent",
+                        "StructProperty",
+                        struct_value("Vector", box_extent),
+                    ),
+                    native_field("SphereRadius", real, sphere_radius),
+                ]);
+            }
+            "SoftObjectPath" | "SoftClassPath" => {
+                let path = self.read_soft_object_path()?;
+                return Ok(vec![native_field(
+                    "AssetPath",
+                    "SoftObjectProperty",
+                    PropertyValue::SoftObject(path),
+                )]);
+            }
+            "RichCurveKey" => {
+                let interp_mode = RichCurveInterpMode::from_index(self.reader.read_u8()?);
+                let tangent_mode = RichCurveTangentMode::from_index(self.reader.read_u8()?);
+                let tangent_weight_mode =
+                    RichCurveTangentWeightMode::from_index(self.reader.read_u8()?);
+
+                let mut properties = vec![
+                    native_field(
+                        "InterpMode",
+                        "ByteProperty",
+                        PropertyValue::Enum(interp_mode.name().to_string()),
+                    ),
+                    native_field(
+                        "TangentMode",
+                        "ByteProperty",
+                        PropertyValue::Enum(tangent_mode.name().to_string()),
+                    ),
+                    native_field(
+                        "TangentWeightMode",
+                        "ByteProperty",
+                        PropertyValue::Enum(tangent_weight_mode.name().to_string()),
+                    ),
+                ];
+                for name in [
+                    "Time",
+                    "Value",
+                    "ArriveTangent",
+                    "ArriveTangentWeight",
+                    "LeaveTangent",
+                    "LeaveTangentWeight",
+                ] {
+                    let value = PropertyValue::Float(self.reader.read_f32::<LittleEndian>()?);
+                    properties.push(native_field(name, "FloatProperty", value));
+                }
+                return Ok(properties);
+            }
+            "SimpleCurveKey" => &[("Time", "FloatProperty"), ("Value", "FloatProperty")],
+            "GameplayTagContainer" => {
+                let count = self.read_element_count()?;
+                let mut tags = Vec::with_capacity(count);
+                for _ in 0..count {
+                    let tag = self.reader.read_fname()?;
+                    let tag = PropertyValue::Name(self.get_name_string(&tag)?);
+                    tags.push(struct_value(
+                        "GameplayTag",
+                        vec![native_field("TagName", "NameProperty", tag)],
+                    ));
+                }
+                return Ok(vec![native_field(
+                    "GameplayTags",
+                    "ArrayProperty",
+                    PropertyValue::Array(tags),
+                )]);
+            }
+            other => return Err(ParseError::UnsupportedPropertyType(other.to_string())),
+        };
+
+        let mut properties = Vec::with_capacity(fields.len());
+        for (name, property_type) in fields {
+            let value = self.read_property_value(&PropertyTypeName::new(property_type))?;
+            properties.push(native_field(name, property_type, value));
+        }
+        Ok(properties)
+    }
+
+    /// Reads an FText, decoding the text histories that carry a source string.
+    pub(crate) fn read_text(&mut self) -> Result<TextValue> {
+        const TEXT_FLAG_CULTURE_INVARIANT: u32 = 0x02;
+
+        let flags = self.reader.read_u32::<LittleEndian>()?;
+        let history_type = self.reader.read_i8()?;
+
+        match history_type {
+            -1 => {
+                let has_invariant_string = self.reader.read_i32::<LittleEndian>()? != 0;
+                let source = if has_invariant_string {
+                    self.reader.read_fstring()?
+                } else {
+                    String::new()
+                };
+                if flags & TEXT_FLAG_CULTURE_INVARIANT != 0 || source.is_empty() {
+                    Ok(TextValue::Invariant(source))
+                } else {
+                    Ok(TextValue::Other(source))
+                }
+            }
+            0 => Ok(TextValue::Localized {
+                namespace: self.reader.read_fstring()?,
+                key: self.reader.read_fstring()?,
+                source: self.reader.read_fstring()?,
+            }),
+            11 => {
+                let table_id = self.reader.read_fname()?;
+                Ok(TextValue::StringTableEntry {
+                    table_id: self.get_name_string(&table_id)?,
+                    key: self.reader.read_fstring()?,
+                })
+            }
+            other => Err(ParseError::UnsupportedTextHistory(other)),
+        }
+    }
+
+    fn object_reference(&mut self, index: i32) -> Result<PropertyValue> {
+        Ok(PropertyValue::Object {
+            index,
+            class_path: self.get_object_class_path(index)?,
+            path: self.get_object_path(index)?,
+        })
+    }
+
+    fn read_script_delegate(&mut self) -> Result<(String, String)> {
+        let object = self.reader.read_i32::<LittleEndian>()?;
+        let function = self.reader.read_fname()?;
+        Ok((
+            self.get_object_path(object)?,
+            self.get_name_string(&function)?,
+        ))
+    }
+
+    /// Reads a container element count, bounded by the bytes left in the file.
+    pub(crate) fn read_element_count(&mut self) -> Result<usize> {
+        let count = self.reader.read_i32::<LittleEndian>()?;
+        let position = self.reader.stream_position()?;
+        if count < 0 || count as u64 > self.package_file_size.saturating_sub(position) {
+            return Err(ParseError::InvalidArraySize(count));
+        }
+        Ok(count as usize)
+    }
+}
+
+/// Structs with a native serializer that older packages don't flag in the tag.
+fn is_native_struct(struct_name: &str) -> bool {
+    matches!(
+        struct_name,
+        "Vector"
+            | "Vector2D"
+            | "Vector4"
+            | "Vector3f"
+            | "Vector2f"
+            | "Vector4f"
+            | "Vector_NetQuantize"
+            | "Vector_NetQuantize10"
+            | "Vector_NetQuantize100"
+            | "Vector_NetQuantizeNormal"
+            | "Rotator"
+            | "Quat"
+            | "Plane"
+            | "Box"
+            | "Box2D"
+            | "BoxSphereBounds"
+            | "LinearColor"
+            | "Color"
+            | "IntPoint"
+            | "IntVector"
+            | "Guid"
+            | "DateTime"
+            | "Timespan"
+            | "FrameNumber"
+            | "SoftObjectPath"
+            | "SoftClassPath"
+            | "GameplayTagContainer"
+            | "RichCurveKey"
+            | "SimpleCurveKey"
+    )
+}
+
+fn native_field(name: &str, property_type: &str, value: PropertyValue) -> Property {
+    Property {
+        name: name.to_string(),
+        type_name: PropertyTypeName::new(property_type),
+        array_index: 0,
+        value,
+    }
+}
+
+fn struct_value(struct_name: &str, properties: Vec<Property>) -> PropertyValue {
+    PropertyValue::Struct {
+        struct_name: struct_name.to_string(),
+        properties,
+    }
+}
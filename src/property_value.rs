@@ -0,0 +1,338 @@
+use crate::property::PropertyTypeName;
+use crate::tag_value::TextValue;
+use crate::unreal_types::SoftObjectPath;
+
+/// A decoded property: its name, full type and value.
+#[derive(Debug, Clone)]
+pub struct Property {
+    pub name: String,
+    pub type_name: PropertyTypeName,
+    pub array_index: i32,
+    pub value: PropertyValue,
+}
+
+impl Property {
+    /// Name as shown in the editor, without the `_<n>_<GUID>` suffix user
+    /// defined struct members carry.
+    pub fn display_name(&self) -> &str {
+        display_property_name(&self.name)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum PropertyValue {
+    Bool(bool),
+    Int8(i8),
+    Int16(i16),
+    Int(i32),
+    Int64(i64),
+    Byte(u8),
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+    Float(f32),
+    Double(f64),
+    Name(String),
+    Str(String),
+    Text(TextValue),
+    /// Enumerator name of a `ByteProperty` or `EnumProperty` with an enum.
+    Enum(String),
+    /// Reference to an import or export, with its class and resolved path.
+    Object {
+        index: i32,
+        class_path: String,
+        path: String,
+    },
+    SoftObject(SoftObjectPath),
+    Struct {
+        struct_name: String,
+        properties: Vec<Property>,
+    },
+    Array(Vec<PropertyValue>),
+    Set(Vec<PropertyValue>),
+    Map(Vec<(PropertyValue, PropertyValue)>),
+    Optional(Option<Box<PropertyValue>>),
+    Delegate {
+        object: String,
+        function: String,
+    },
+    MulticastDelegate(Vec<(String, String)>),
+    FieldPath(Vec<String>),
+    /// Value that could not be decoded, kept as serialized.
+    Raw(Vec<u8>),
+}
+
+impl PropertyValue {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropertyValue::Name(value) | PropertyValue::Str(value) | PropertyValue::Enum(value) => {
+                Some(value)
+            }
+            PropertyValue::Text(TextValue::Localized { source, .. })
+            | PropertyValue::Text(TextValue::Invariant(source)) => Some(source),
+            PropertyValue::Object { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            PropertyValue::Int8(v) => Some(v as f64),
+            PropertyValue::Int16(v) => Some(v as f64),
+            PropertyValue::Int(v) => Some(v as f64),
+            PropertyValue::Int64(v) => Some(v as f64),
+            PropertyValue::Byte(v) => Some(v as f64),
+            PropertyValue::UInt16(v) => Some(v as f64),
+            PropertyValue::UInt32(v) => Some(v as f64),
+            PropertyValue::UInt64(v) => Some(v as f64),
+            PropertyValue::Float(v) => Some(v as f64),
+            PropertyValue::Double(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            PropertyValue::Int8(v) => Some(v as i64),
+            PropertyValue::Int16(v) => Some(v as i64),
+            PropertyValue::Int(v) => Some(v as i64),
+            PropertyValue::Int64(v) => Some(v),
+            PropertyValue::Byte(v) => Some(v as i64),
+            PropertyValue::UInt16(v) => Some(v as i64),
+            PropertyValue::UInt32(v) => Some(v as i64),
+            PropertyValue::UInt64(v) => Some(v as i64),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            PropertyValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Package index of an object reference.
+    pub fn as_object_index(&self) -> Option<i32> {
+        match self {
+            PropertyValue::Object { index, .. } => Some(*index),
+            _ => None,
+        }
+    }
+
+    /// Path of an object or soft object reference.
+    pub fn as_object_path(&self) -> Option<String> {
+        match self {
+            PropertyValue::Object { path, .. } if !path.is_empty() => Some(path.clone()),
+            PropertyValue::SoftObject(path) if !path.is_null() => Some(path.to_string()),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[PropertyValue]> {
+        match self {
+            PropertyValue::Array(values) | PropertyValue::Set(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn as_struct(&self) -> Option<&[Property]> {
+        match self {
+            PropertyValue::Struct { properties, .. } => Some(properties),
+            _ => None,
+        }
+    }
+
+    /// Looks up a member of a struct value by (display) name.
+    pub fn field(&self, name: &str) -> Option<&PropertyValue> {
+        find_property(self.as_struct()?, name)
+    }
+
+    /// Formats the value the way the editor exports property text, e.g. for
+    /// DataTable CSV export: `(X=1.000000,Y=2.000000)`, `Class'/Game/Path'`.
+    pub fn export_text(&self) -> String {
+        match self {
+            PropertyValue::Bool(value) => if *value { "True" } else { "False" }.to_string(),
+            PropertyValue::Int8(v) => v.to_string(),
+            PropertyValue::Int16(v) => v.to_string(),
+            PropertyValue::Int(v) => v.to_string(),
+            PropertyValue::Int64(v) => v.to_string(),
+            PropertyValue::Byte(v) => v.to_string(),
+            PropertyValue::UInt16(v) => v.to_string(),
+            PropertyValue::UInt32(v) => v.to_string(),
+            PropertyValue::UInt64(v) => v.to_string(),
+            PropertyValue::Float(v) => format!("{:.6}", v),
+            PropertyValue::Double(v) => format!("{:.6}", v),
+            PropertyValue::Name(v) | PropertyValue::Str(v) => v.clone(),
+            PropertyValue::Enum(v) => v.rsplit("::").next().unwrap_or(v).to_string(),
+            PropertyValue::Text(text) => match text {
+                TextValue::Localized {
+                    namespace,
+                    key,
+                    source,
+                } => format!(
+                    "NSLOCTEXT(\"{}\", \"{}\", \"{}\")",
+                    escape(namespace),
+                    escape(key),
+                    escape(source)
+                ),
+                TextValue::Invariant(source) => format!("INVTEXT(\"{}\")", escape(source)),
+                TextValue::StringTableEntry { table_id, key } => {
+                    format!("LOCTABLE(\"{}\", \"{}\")", escape(table_id), escape(key))
+                }
+                TextValue::Other(value) => value.clone(),
+            },
+            PropertyValue::Object {
+                class_path, path, ..
+            } => {
+                if path.is_empty() {
+                    "None".to_string()
+                } else if class_path.is_empty() {
+                    path.clone()
+                } else {
+                    format!("{}'{}'", class_path, path)
+                }
+            }
+            PropertyValue::SoftObject(path) => {
+                if path.is_null() {
+                    "None".to_string()
+                } else {
+                    path.to_string()
+                }
+            }
+            PropertyValue::Struct { properties, .. } => {
+                let fields: Vec<String> = properties
+                    .iter()
+                    .map(|p| format!("{}={}", p.display_name(), quote_export_text(&p.value)))
+                    .collect();
+                format!("({})", fields.join(","))
+            }
+            PropertyValue::Array(values) | PropertyValue::Set(values) => {
+                let values: Vec<String> = values.iter().map(quote_export_text).collect();
+                format!("({})", values.join(","))
+            }
+            PropertyValue::Map(entries) => {
+                let entries: Vec<String> = entries
+                    .iter()
+                    .map(|(k, v)| format!("({}, {})", quote_export_text(k), quote_export_text(v)))
+                    .collect();
+                format!("({})", entries.join(","))
+            }
+            PropertyValue::Optional(value) => {
+                value.as_ref().map(|v| v.export_text()).unwrap_or_default()
+            }
+            PropertyValue::Delegate { object, function } => format!("{}.{}", object, function),
+            PropertyValue::MulticastDelegate(delegates) => {
+                let delegates: Vec<String> = delegates
+                    .iter()
+                    .map(|(object, function)| format!("{}.{}", object, function))
+                    .collect();
+                format!("({})", delegates.join(","))
+            }
+            PropertyValue::FieldPath(path) => path.join("."),
+            PropertyValue::Raw(data) => data.iter().map(|b| format!("{:02X}", b)).collect(),
+        }
+    }
+
+    /// Formats the value as JSON, matching the editor's DataTable JSON export
+    /// for the common property types.
+    pub fn to_json(&self) -> String {
+        match self {
+            PropertyValue::Bool(value) => value.to_string(),
+            PropertyValue::Float(v) => json_number(*v as f64),
+            PropertyValue::Double(v) => json_number(*v),
+            PropertyValue::Struct { properties, .. } => properties_to_json(properties),
+            PropertyValue::Array(values) | PropertyValue::Set(values) => {
+                let values: Vec<String> = values.iter().map(|v| v.to_json()).collect();
+                format!("[{}]", values.join(","))
+            }
+            PropertyValue::Map(entries) => {
+                let entries: Vec<String> = entries
+                    .iter()
+                    .map(|(k, v)| format!("{}:{}", json_string(&k.export_text()), v.to_json()))
+                    .collect();
+                format!("{{{}}}", entries.join(","))
+            }
+            PropertyValue::Optional(value) => value
+                .as_ref()
+                .map(|v| v.to_json())
+                .unwrap_or_else(|| "null".to_string()),
+            PropertyValue::Text(_) => json_string(self.as_str().unwrap_or_default()),
+            value if value.as_i64().is_some() => value.export_text(),
+            value => json_string(&value.export_text()),
+        }
+    }
+}
+
+/// Looks up a property by name, matching either the raw or display name.
+pub fn find_property<'a>(properties: &'a [Property], name: &str) -> Option<&'a PropertyValue> {
+    properties
+        .iter()
+        .find(|p| p.name == name || p.display_name() == name)
+        .map(|p| &p.value)
+}
+
+/// Formats properties as a JSON object keyed by display name.
+pub fn properties_to_json(properties: &[Property]) -> String {
+    let fields: Vec<String> = properties
+        .iter()
+        .map(|p| format!("{}:{}", json_string(p.display_name()), p.value.to_json()))
+        .collect();
+    format!("{{{}}}", fields.join(","))
+}
+
+/// Strips the `_<n>_<32 hex digit GUID>` suffix of user defined struct members.
+pub fn display_property_name(name: &str) -> &str {
+    let Some((rest, guid)) = name.rsplit_once('_') else {
+        return name;
+    };
+    if guid.len() != 32 || !guid.chars().all(|c| c.is_ascii_hexdigit()) {
+        return name;
+    }
+    match rest.rsplit_once('_') {
+        Some((display, number)) if number.chars().all(|c| c.is_ascii_digit()) => display,
+        _ => name,
+    }
+}
+
+pub fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_string()
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Quotes nested string values the way struct export text does.
+fn quote_export_text(value: &PropertyValue) -> String {
+    match value {
+        PropertyValue::Str(v) => format!("\"{}\"", escape(v)),
+        PropertyValue::Object { path, .. } if !path.is_empty() => {
+            format!("\"{}\"", value.export_text())
+        }
+        PropertyValue::SoftObject(path) if !path.is_null() => format!("\"{}\"", path),
+        value => value.export_text(),
+    }
+}
@@ -0,0 +1,182 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufReader, Read, Seek};
+use std::path::{Path, PathBuf};
+
+use crate::errors::{ParseError, Result};
+use crate::external_actors::collect_packages;
+use crate::parser::UassetParser;
+use crate::property_value::PropertyValue;
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Returns whether the package only holds redirectors, as left behind by
+    /// renaming or moving assets in the editor.
+    pub fn is_redirector_package(&mut self) -> Result<bool> {
+        let redirectors = self.find_exports_by_class("ObjectRedirector")?.len();
+        Ok(redirectors > 0 && redirectors == self.get_exports()?.len())
+    }
+
+    /// Reads the destination object path of an `ObjectRedirector` export
+    /// (0-based index into the export table), or `None` if the destination is
+    /// null.
+    pub fn read_redirector_destination(&mut self, export_index: usize) -> Result<Option<String>> {
+        let class_name = self.get_export_class_name(export_index)?;
+        if class_name != "ObjectRedirector" {
+            return Err(ParseError::UnexpectedExportClass(class_name));
+        }
+
+        // The destination is serialized after the properties; older packages
+        // may also carry it as a tagged property
+        let properties = self.seek_export_properties(export_index)?;
+        let destination = match properties
+            .get("DestinationObject")
+            .and_then(PropertyValue::as_object_index)
+        {
+            Some(index) => index,
+            None => self.reader.read_i32::<LittleEndian>()?,
+        };
+
+        if destination == 0 {
+            return Ok(None);
+        }
+        Ok(Some(self.get_object_path(destination)?))
+    }
+
+    /// Returns the redirectors of the package as (redirector object path,
+    /// destination object path) pairs.
+    pub fn get_redirectors(&mut self) -> Result<Vec<(String, Option<String>)>> {
+        let mut redirectors = Vec::new();
+        for export_index in self.find_exports_by_class("ObjectRedirector")? {
+            let source = self.get_object_path(export_index as i32 + 1)?;
+            redirectors.push((source, self.read_redirector_destination(export_index)?));
+        }
+        Ok(redirectors)
+    }
+}
+
+/// Outcome of following the redirectors from an object path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Redirection {
+    /// The path is not redirected.
+    Direct(String),
+    /// The chain ends at an object that isn't a redirector. The chain starts
+    /// with the requested path and ends with the final destination.
+    Resolved(Vec<String>),
+    /// The chain loops back on itself; the last path repeats an earlier one.
+    Cycle(Vec<String>),
+    /// The chain ends at a null destination or a package that doesn't exist.
+    Dangling(Vec<String>),
+}
+
+impl Redirection {
+    /// Final object path, if the path resolves.
+    pub fn destination(&self) -> Option<&str> {
+        match self {
+            Redirection::Direct(path) => Some(path),
+            Redirection::Resolved(chain) => chain.last().map(String::as_str),
+            Redirection::Cycle(_) | Redirection::Dangling(_) => None,
+        }
+    }
+}
+
+/// Project-wide view of redirectors, used to resolve references through
+/// chains of renamed assets.
+#[derive(Debug, Default)]
+pub struct RedirectorResolver {
+    /// Redirector object path to destination object path, `None` if null.
+    pub redirects: HashMap<String, Option<String>>,
+    /// Package names of all scanned packages.
+    pub packages: HashSet<String>,
+    /// Packages that could not be parsed, with the error.
+    pub errors: Vec<(PathBuf, ParseError)>,
+}
+
+impl RedirectorResolver {
+    /// Scans the `.uasset` and `.umap` packages under a content folder.
+    pub fn scan(content_root: &Path) -> Result<Self> {
+        let mut files = Vec::new();
+        collect_packages(content_root, &mut files)?;
+        files.sort();
+
+        let mut resolver = RedirectorResolver::default();
+        for path in files {
+            let result = File::open(&path)
+                .map_err(ParseError::from)
+                .and_then(|file| UassetParser::new(BufReader::new(file), true))
+                .and_then(|mut parser| resolver.add_package(&mut parser));
+            if let Err(error) = result {
+                resolver.errors.push((path, error));
+            }
+        }
+        Ok(resolver)
+    }
+
+    /// Records a package and the redirectors it holds.
+    pub fn add_package<R: Read + Seek>(&mut self, parser: &mut UassetParser<R>) -> Result<()> {
+        for (source, destination) in parser.get_redirectors()? {
+            self.redirects.insert(source, destination);
+        }
+        self.packages.insert(parser.summary.package_name.clone());
+        Ok(())
+    }
+
+    /// Follows the redirector chain starting at an object path.
+    pub fn resolve(&self, object_path: &str) -> Redirection {
+        let mut chain = vec![object_path.to_string()];
+        let mut current = object_path;
+
+        while let Some(destination) = self.redirects.get(current) {
+            let Some(destination) = destination else {
+                return Redirection::Dangling(chain);
+            };
+            let is_cycle = chain.contains(destination);
+            chain.push(destination.clone());
+            if is_cycle {
+                return Redirection::Cycle(chain);
+            }
+            current = destination;
+        }
+
+        if chain.len() == 1 {
+            Redirection::Direct(chain.pop().unwrap_or_default())
+        } else if self.is_missing(current) {
+            Redirection::Dangling(chain)
+        } else {
+            Redirection::Resolved(chain)
+        }
+    }
+
+    /// Redirectors whose chains loop or end nowhere.
+    pub fn broken_redirectors(&self) -> Vec<Redirection> {
+        let mut sources: Vec<&String> = self.redirects.keys().collect();
+        sources.sort();
+        sources
+            .into_iter()
+            .map(|source| self.resolve(source))
+            .filter(|resolution| {
+                matches!(resolution, Redirection::Cycle(_) | Redirection::Dangling(_))
+            })
+            .collect()
+    }
+
+    /// Whether an object path points into a scanned mount point (such as
+    /// `/Game`) but no scanned package has its package name.
+    fn is_missing(&self, object_path: &str) -> bool {
+        let package = object_path.split(['.', ':']).next().unwrap_or(object_path);
+        let mount = mount_point(package);
+        !self.packages.contains(package)
+            && self
+                .packages
+                .iter()
+                .any(|scanned| mount_point(scanned) == mount)
+    }
+}
+
+/// Mount point of a package name: `/Game` for `/Game/Maps/Main`.
+fn mount_point(package: &str) -> &str {
+    match package.get(1..).and_then(|rest| rest.find('/')) {
+        Some(end) => &package[..end + 1],
+        None => package,
+    }
+}
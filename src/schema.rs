@@ -0,0 +1,484 @@
+//! Reflection data of `UStruct` exports: the `FField` property definitions of
+//! classes, script structs and functions, as serialized in editor packages.
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{Read, Seek};
+
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::reader::UassetReader;
+
+const PKG_FILTER_EDITOR_ONLY: u32 = 0x8000_0000;
+
+// EPropertyFlags
+pub const CPF_CONST_PARM: u64 = 0x0000_0000_0000_0002;
+pub const CPF_PARM: u64 = 0x0000_0000_0000_0080;
+pub const CPF_OUT_PARM: u64 = 0x0000_0000_0000_0100;
+pub const CPF_RETURN_PARM: u64 = 0x0000_0000_0000_0400;
+pub const CPF_REFERENCE_PARM: u64 = 0x0000_0000_0800_0000;
+
+/// Maximum nesting of container properties, guarding against corrupt data.
+const MAX_FIELD_DEPTH: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructKind {
+    Class,
+    ScriptStruct,
+    Function,
+}
+
+impl StructKind {
+    /// Classifies an export class such as `BlueprintGeneratedClass`,
+    /// `UserDefinedStruct` or `DelegateFunction`.
+    pub fn from_class_name(class_name: &str) -> Option<Self> {
+        match class_name {
+            "Class"
+            | "BlueprintGeneratedClass"
+            | "WidgetBlueprintGeneratedClass"
+            | "AnimBlueprintGeneratedClass" => Some(StructKind::Class),
+            "ScriptStruct" | "UserDefinedStruct" => Some(StructKind::ScriptStruct),
+            "Function" | "DelegateFunction" | "SparseDelegateFunction" => {
+                Some(StructKind::Function)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// An `FProperty` definition.
+#[derive(Debug, Clone, Default)]
+pub struct PropertySchema {
+    pub name: String,
+    /// Field class, e.g. `IntProperty` or `ArrayProperty`.
+    pub type_name: String,
+    pub object_flags: u32,
+    pub property_flags: u64,
+    pub array_dim: i32,
+    pub element_size: i32,
+    pub rep_index: u16,
+    pub rep_notify_func: Option<String>,
+    /// Object the type refers to: the struct of a `StructProperty`, the enum of
+    /// an `EnumProperty`/`ByteProperty`, the class of object properties or the
+    /// signature of delegates. Field class name for `FieldPathProperty`.
+    pub reference: Option<String>,
+    /// Meta class of `ClassProperty` and `SoftClassProperty`.
+    pub meta_class: Option<String>,
+    /// Inner, element, key/value or underlying properties of container and
+    /// enum types.
+    pub inner: Vec<PropertySchema>,
+    /// Editor metadata such as `Category` or `ToolTip`.
+    pub metadata: Vec<(String, String)>,
+}
+
+impl PropertySchema {
+    pub fn has_flag(&self, flag: u64) -> bool {
+        self.property_flags & flag != 0
+    }
+
+    pub fn metadata_value(&self, key: &str) -> Option<&str> {
+        self.metadata
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Formats the type the way it reads in C++ reflection, using object
+    /// names: `TArray<Vector>`, `TSubclassOf<Actor>`, `TMap<FName, int32>`.
+    pub fn cpp_type(&self) -> String {
+        let reference = short_object_name(self.reference.as_deref().unwrap_or("UObject"));
+        let meta_class = short_object_name(self.meta_class.as_deref().unwrap_or("UObject"));
+        let inner = |index: usize| {
+            self.inner
+                .get(index)
+                .map(|p| p.cpp_type())
+                .unwrap_or_else(|| "?".to_string())
+        };
+
+        match self.type_name.as_str() {
+            "BoolProperty" => "bool".to_string(),
+            "Int8Property" => "int8".to_string(),
+            "Int16Property" => "int16".to_string(),
+            "IntProperty" => "int32".to_string(),
+            "Int64Property" => "int64".to_string(),
+            "UInt16Property" => "uint16".to_string(),
+            "UInt32Property" => "uint32".to_string(),
+            "UInt64Property" => "uint64".to_string(),
+            "FloatProperty" => "float".to_string(),
+            "DoubleProperty" => "double".to_string(),
+            "ByteProperty" if self.reference.is_some() => {
+                format!("TEnumAsByte<{}>", reference)
+            }
+            "ByteProperty" => "uint8".to_string(),
+            "EnumProperty" => reference.to_string(),
+            "StrProperty" => "FString".to_string(),
+            "NameProperty" => "FName".to_string(),
+            "TextProperty" => "FText".to_string(),
+            "StructProperty" => reference.to_string(),
+            "ObjectProperty" | "ObjectPtrProperty" => format!("{}*", reference),
+            "WeakObjectProperty" => format!("TWeakObjectPtr<{}>", reference),
+            "LazyObjectProperty" => format!("TLazyObjectPtr<{}>", reference),
+            "SoftObjectProperty" => format!("TSoftObjectPtr<{}>", reference),
+            "ClassProperty" | "ClassPtrProperty" => format!("TSubclassOf<{}>", meta_class),
+            "SoftClassProperty" => format!("TSoftClassPtr<{}>", meta_class),
+            "InterfaceProperty" => format!("TScriptInterface<{}>", reference),
+            "FieldPathProperty" => format!("TFieldPath<{}>", reference),
+            "ArrayProperty" => format!("TArray<{}>", inner(0)),
+            "SetProperty" => format!("TSet<{}>", inner(0)),
+            "MapProperty" => format!("TMap<{}, {}>", inner(0), inner(1)),
+            "OptionalProperty" => format!("TOptional<{}>", inner(0)),
+            "DelegateProperty" => format!("Delegate<{}>", reference),
+            "MulticastDelegateProperty"
+            | "MulticastInlineDelegateProperty"
+            | "MulticastSparseDelegateProperty" => format!("MulticastDelegate<{}>", reference),
+            other => other.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImplementedInterface {
+    pub class: String,
+    pub pointer_offset: i32,
+    pub implemented_by_k2: bool,
+}
+
+/// `UClass` data serialized after the struct.
+#[derive(Debug, Clone, Default)]
+pub struct ClassSchema {
+    pub class_flags: u32,
+    pub class_within: String,
+    pub config_name: String,
+    pub generated_by: String,
+    /// Function names with the object path of each function.
+    pub functions: Vec<(String, String)>,
+    pub interfaces: Vec<ImplementedInterface>,
+    pub default_object: String,
+}
+
+/// A `UClass`, `UScriptStruct` or `UFunction` definition.
+#[derive(Debug, Clone)]
+pub struct StructSchema {
+    pub kind: StructKind,
+    /// Export class, e.g. `BlueprintGeneratedClass`.
+    pub class_name: String,
+    pub name: String,
+    pub path: String,
+    pub super_struct: Option<String>,
+    /// Object paths of the `UField` children, i.e. functions of a class.
+    pub children: Vec<String>,
+    pub properties: Vec<PropertySchema>,
+    /// Size of the struct's Kismet bytecode in memory.
+    pub bytecode_size: i32,
+    /// `EStructFlags` of script structs.
+    pub struct_flags: Option<u32>,
+    /// `EFunctionFlags` of functions.
+    pub function_flags: Option<u32>,
+    /// Class data; `None` for other kinds or if it could not be decoded.
+    pub class: Option<ClassSchema>,
+}
+
+impl StructSchema {
+    /// Parameters of a function, excluding the return value.
+    pub fn parameters(&self) -> impl Iterator<Item = &PropertySchema> {
+        self.properties
+            .iter()
+            .filter(|p| p.has_flag(CPF_PARM) && !p.has_flag(CPF_RETURN_PARM))
+    }
+
+    pub fn return_property(&self) -> Option<&PropertySchema> {
+        self.properties.iter().find(|p| p.has_flag(CPF_RETURN_PARM))
+    }
+
+    /// Formats a function signature, e.g. `bool CanJump(int32 Count, out Vector Velocity)`.
+    pub fn signature(&self) -> String {
+        let parameters: Vec<String> = self
+            .parameters()
+            .map(|p| {
+                let out = p.has_flag(CPF_OUT_PARM) && !p.has_flag(CPF_CONST_PARM);
+                let prefix = if out { "out " } else { "" };
+                format!("{}{} {}", prefix, p.cpp_type(), p.name)
+            })
+            .collect();
+        let return_type = self
+            .return_property()
+            .map(|p| p.cpp_type())
+            .unwrap_or_else(|| "void".to_string());
+        format!("{} {}({})", return_type, self.name, parameters.join(", "))
+    }
+
+    /// Formats the definition as a declaration listing its members.
+    pub fn declaration(&self) -> String {
+        if self.kind == StructKind::Function {
+            return self.signature();
+        }
+
+        let keyword = match self.kind {
+            StructKind::Class => "class",
+            _ => "struct",
+        };
+        let mut text = format!("{} {}", keyword, self.name);
+        if let Some(super_struct) = &self.super_struct {
+            text.push_str(&format!(" : {}", short_object_name(super_struct)));
+        }
+        text.push_str("\n{\n");
+        for property in &self.properties {
+            let dim = if property.array_dim > 1 {
+                format!("[{}]", property.array_dim)
+            } else {
+                String::new()
+            };
+            text.push_str(&format!(
+                "    {} {}{};\n",
+                property.cpp_type(),
+                property.name,
+                dim
+            ));
+        }
+        if let Some(class) = &self.class {
+            for (name, _) in &class.functions {
+                text.push_str(&format!("    function {};\n", name));
+            }
+        }
+        text.push('}');
+        text
+    }
+}
+
+/// Object name of a path: `Vector` for `/Script/CoreUObject.Vector`.
+fn short_object_name(path: &str) -> &str {
+    path.rsplit(['.', ':']).next().unwrap_or(path)
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads the definition of a class, script struct or function export
+    /// (0-based index into the export table).
+    pub fn read_struct_schema(&mut self, export_index: usize) -> Result<StructSchema> {
+        let class_name = self.get_export_class_name(export_index)?;
+        let kind = StructKind::from_class_name(&class_name)
+            .ok_or_else(|| ParseError::UnexpectedExportClass(class_name.clone()))?;
+
+        let package_index = export_index as i32 + 1;
+        let name = self.get_object_name(package_index)?;
+        let path = self.get_object_path(package_index)?;
+
+        self.seek_export_properties(export_index)?;
+
+        // UStruct
+        let super_index = self.reader.read_i32::<LittleEndian>()?;
+        let super_struct = if super_index != 0 {
+            Some(self.get_object_path(super_index)?)
+        } else {
+            None
+        };
+
+        let child_indices = self
+            .reader
+            .read_tarray(|r| Ok(r.read_i32::<LittleEndian>()?), 65536)?;
+        let mut children = Vec::with_capacity(child_indices.len());
+        for index in child_indices {
+            children.push(self.get_object_path(index)?);
+        }
+
+        let n_properties = self.read_element_count()?;
+        let mut properties = Vec::with_capacity(n_properties);
+        for _ in 0..n_properties {
+            let field = self.read_field_schema(0)?;
+            properties.extend(field);
+        }
+
+        let bytecode_size = self.reader.read_i32::<LittleEndian>()?;
+        let storage_size = self.reader.read_i32::<LittleEndian>()?;
+        self.reader.skip_bytes(storage_size as i64)?;
+
+        let mut schema = StructSchema {
+            kind,
+            class_name,
+            name,
+            path,
+            super_struct,
+            children,
+            properties,
+            bytecode_size,
+            struct_flags: None,
+            function_flags: None,
+            class: None,
+        };
+
+        match kind {
+            StructKind::ScriptStruct => {
+                schema.struct_flags = Some(self.reader.read_u32::<LittleEndian>()?);
+            }
+            StructKind::Function => {
+                let flags = self.reader.read_u32::<LittleEndian>()?;
+                schema.function_flags = Some(flags);
+            }
+            StructKind::Class => {
+                schema.class = self.read_class_schema().ok();
+            }
+        }
+
+        Ok(schema)
+    }
+
+    /// Reads the definitions of all class, script struct and function
+    /// exports. Exports that fail to decode are skipped.
+    pub fn read_struct_schemas(&mut self) -> Result<Vec<StructSchema>> {
+        let n_exports = self.get_exports()?.len();
+        let mut schemas = Vec::new();
+        for export_index in 0..n_exports {
+            let class_name = self.get_export_class_name(export_index)?;
+            if StructKind::from_class_name(&class_name).is_some()
+                && let Ok(schema) = self.read_struct_schema(export_index)
+            {
+                schemas.push(schema);
+            }
+        }
+        Ok(schemas)
+    }
+
+    fn read_class_schema(&mut self) -> Result<ClassSchema> {
+        let n_functions = self.read_element_count()?;
+        let mut functions = Vec::with_capacity(n_functions);
+        for _ in 0..n_functions {
+            let name = self.reader.read_fname()?;
+            let name = self.get_name_string(&name)?;
+            let function = self.reader.read_i32::<LittleEndian>()?;
+            functions.push((name, self.get_object_path(function)?));
+        }
+
+        let class_flags = self.reader.read_u32::<LittleEndian>()?;
+        let class_within = self.reader.read_i32::<LittleEndian>()?;
+        let config_name = self.reader.read_fname()?;
+        let generated_by = self.reader.read_i32::<LittleEndian>()?;
+
+        let n_interfaces = self.read_element_count()?;
+        let mut interfaces = Vec::with_capacity(n_interfaces);
+        for _ in 0..n_interfaces {
+            let class = self.reader.read_i32::<LittleEndian>()?;
+            interfaces.push(ImplementedInterface {
+                class: self.get_object_path(class)?,
+                pointer_offset: self.reader.read_i32::<LittleEndian>()?,
+                implemented_by_k2: self.reader.read_u32::<LittleEndian>()? != 0,
+            });
+        }
+
+        self.reader.read_u32::<LittleEndian>()?; // bDeprecatedForceScriptOrder
+        self.reader.read_fname()?; // Dummy
+        self.reader.read_u32::<LittleEndian>()?; // bCooked
+        let default_object = self.reader.read_i32::<LittleEndian>()?;
+
+        Ok(ClassSchema {
+            class_flags,
+            class_within: self.get_object_path(class_within)?,
+            config_name: self.get_name_string(&config_name)?,
+            generated_by: self.get_object_path(generated_by)?,
+            functions,
+            interfaces,
+            default_object: self.get_object_path(default_object)?,
+        })
+    }
+
+    /// Reads an `FField` preceded by its field class name, as written by
+    /// `SerializeSingleField`. Returns `None` for a `None` class name.
+    fn read_field_schema(&mut self, depth: usize) -> Result<Option<PropertySchema>> {
+        if depth > MAX_FIELD_DEPTH {
+            return Err(ParseError::UnsupportedPropertyType(
+                "nested field too deep".to_string(),
+            ));
+        }
+
+        let type_name = self.reader.read_fname()?;
+        let type_name = self.get_name_string(&type_name)?;
+        if type_name == "None" {
+            return Ok(None);
+        }
+
+        // FField
+        let name = self.reader.read_fname()?;
+        let mut property = PropertySchema {
+            name: self.get_name_string(&name)?,
+            type_name,
+            object_flags: self.reader.read_u32::<LittleEndian>()?,
+            ..Default::default()
+        };
+
+        if self.summary.package_flags & PKG_FILTER_EDITOR_ONLY == 0
+            && self.reader.read_u32::<LittleEndian>()? != 0
+        {
+            let n_metadata = self.read_element_count()?;
+            for _ in 0..n_metadata {
+                let key = self.reader.read_fname()?;
+                let key = self.get_name_string(&key)?;
+                property.metadata.push((key, self.reader.read_fstring()?));
+            }
+        }
+
+        // FProperty
+        property.array_dim = self.reader.read_i32::<LittleEndian>()?;
+        property.element_size = self.reader.read_i32::<LittleEndian>()?;
+        property.property_flags = self.reader.read_u64::<LittleEndian>()?;
+        property.rep_index = self.reader.read_u16::<LittleEndian>()?;
+        let rep_notify_func = self.reader.read_fname()?;
+        let rep_notify_func = self.get_name_string(&rep_notify_func)?;
+        if rep_notify_func != "None" {
+            property.rep_notify_func = Some(rep_notify_func);
+        }
+        self.reader.read_u8()?; // BlueprintReplicationCondition
+
+        match property.type_name.as_str() {
+            "BoolProperty" => {
+                // FieldSize, ByteOffset, ByteMask, FieldMask, BoolSize, NativeBool
+                self.reader.skip_bytes(6)?;
+            }
+            "Int8Property" | "Int16Property" | "IntProperty" | "Int64Property"
+            | "UInt16Property" | "UInt32Property" | "UInt64Property" | "FloatProperty"
+            | "DoubleProperty" | "StrProperty" | "NameProperty" | "TextProperty" => {}
+            "ByteProperty" => {
+                property.reference = self.read_schema_reference()?;
+            }
+            "EnumProperty" => {
+                property.reference = self.read_schema_reference()?;
+                property.inner.extend(self.read_field_schema(depth + 1)?);
+            }
+            "StructProperty"
+            | "ObjectProperty"
+            | "ObjectPtrProperty"
+            | "WeakObjectProperty"
+            | "LazyObjectProperty"
+            | "SoftObjectProperty"
+            | "InterfaceProperty"
+            | "DelegateProperty"
+            | "MulticastDelegateProperty"
+            | "MulticastInlineDelegateProperty"
+            | "MulticastSparseDelegateProperty" => {
+                property.reference = self.read_schema_reference()?;
+            }
+            "ClassProperty" | "ClassPtrProperty" | "SoftClassProperty" => {
+                property.reference = self.read_schema_reference()?;
+                property.meta_class = self.read_schema_reference()?;
+            }
+            "FieldPathProperty" => {
+                let field_class = self.reader.read_fname()?;
+                property.reference = Some(self.get_name_string(&field_class)?);
+            }
+            "ArrayProperty" | "SetProperty" | "OptionalProperty" => {
+                property.inner.extend(self.read_field_schema(depth + 1)?);
+            }
+            "MapProperty" => {
+                property.inner.extend(self.read_field_schema(depth + 1)?);
+                property.inner.extend(self.read_field_schema(depth + 1)?);
+            }
+            other => return Err(ParseError::UnsupportedPropertyType(other.to_string())),
+        }
+
+        Ok(Some(property))
+    }
+
+    fn read_schema_reference(&mut self) -> Result<Option<String>> {
+        let index = self.reader.read_i32::<LittleEndian>()?;
+        if index == 0 {
+            return Ok(None);
+        }
+        Ok(Some(self.get_object_path(index)?))
+    }
+}
@@ -0,0 +1,177 @@
+use std::io::{Read, Seek};
+
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::property_value::PropertyValue;
+
+/// Audio settings of a `USoundWave`, from its properties and the package's
+/// asset registry tags.
+#[derive(Debug, Clone, Default)]
+pub struct SoundWaveInfo {
+    /// Length in seconds.
+    pub duration: Option<f64>,
+    pub sample_rate: Option<u32>,
+    pub num_channels: Option<u32>,
+    pub compression_quality: i32,
+    /// `ESoundAssetCompressionType` enumerator, when not the default.
+    pub compression_type: Option<String>,
+    pub streaming: bool,
+    pub seekable_streaming: bool,
+    /// `ESoundWaveLoadingBehavior` enumerator.
+    pub loading_behavior: String,
+    pub looping: bool,
+    pub sound_class: Option<String>,
+}
+
+/// A `USoundNode` of a sound cue graph.
+#[derive(Debug, Clone, Default)]
+pub struct SoundCueNode {
+    /// Package index of the node export.
+    pub index: i32,
+    pub class_name: String,
+    pub name: String,
+    /// Package indices of the child nodes, 0 for unconnected inputs.
+    pub children: Vec<i32>,
+    /// Sound wave played by `SoundNodeWavePlayer` nodes.
+    pub sound_wave: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SoundCueGraph {
+    /// Package index of the output node, 0 if the cue is empty.
+    pub first_node: i32,
+    pub nodes: Vec<SoundCueNode>,
+    pub duration: Option<f64>,
+    pub volume_multiplier: f64,
+    pub pitch_multiplier: f64,
+}
+
+impl SoundCueGraph {
+    pub fn node(&self, index: i32) -> Option<&SoundCueNode> {
+        self.nodes.iter().find(|node| node.index == index)
+    }
+
+    /// Parent to child connections between package indices.
+    pub fn connections(&self) -> Vec<(i32, i32)> {
+        self.nodes
+            .iter()
+            .flat_map(|node| {
+                node.children
+                    .iter()
+                    .filter(|child| **child != 0)
+                    .map(|child| (node.index, *child))
+            })
+            .collect()
+    }
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads the settings of a `SoundWave` export (0-based index into the
+    /// export table). Properties left at their defaults are not serialized, so
+    /// the asset registry tags fill in duration, sample rate and channels.
+    pub fn read_sound_wave(&mut self, export_index: usize) -> Result<SoundWaveInfo> {
+        let class_name = self.get_export_class_name(export_index)?;
+        if class_name != "SoundWave" {
+            return Err(ParseError::UnexpectedExportClass(class_name));
+        }
+
+        let properties = self.read_export_properties(export_index)?;
+        let tags = self.get_export_asset_registry_data(export_index)?;
+        let number = |name: &str| {
+            properties
+                .get(name)
+                .and_then(PropertyValue::as_f64)
+                .or_else(|| {
+                    tags.as_ref()
+                        .and_then(|data| data.tag_value(name))
+                        .and_then(|value| value.as_f64())
+                })
+        };
+        let flag = |name: &str| {
+            properties
+                .get(name)
+                .and_then(PropertyValue::as_bool)
+                .unwrap_or_default()
+        };
+        let enumerator = |name: &str| {
+            properties
+                .get(name)
+                .and_then(PropertyValue::as_str)
+                .map(|value| value.rsplit("::").next().unwrap_or(value).to_string())
+        };
+
+        Ok(SoundWaveInfo {
+            duration: number("Duration"),
+            sample_rate: number("SampleRate").map(|rate| rate as u32),
+            num_channels: number("NumChannels").map(|channels| channels as u32),
+            compression_quality: number("CompressionQuality").map_or(40, |q| q as i32),
+            compression_type: enumerator("SoundAssetCompressionType"),
+            streaming: flag("bStreaming"),
+            seekable_streaming: flag("bSeekableStreaming"),
+            loading_behavior: enumerator("LoadingBehavior")
+                .unwrap_or_else(|| "Inherited".to_string()),
+            looping: flag("bLooping"),
+            sound_class: properties
+                .get("SoundClassObject")
+                .and_then(PropertyValue::as_object_path),
+        })
+    }
+
+    /// Reads the node graph of a `SoundCue` export (0-based index into the
+    /// export table) from the `SoundNode` exports it owns.
+    pub fn read_sound_cue(&mut self, export_index: usize) -> Result<SoundCueGraph> {
+        let class_name = self.get_export_class_name(export_index)?;
+        if class_name != "SoundCue" {
+            return Err(ParseError::UnexpectedExportClass(class_name));
+        }
+
+        let properties = self.read_export_properties(export_index)?;
+        let mut graph = SoundCueGraph {
+            first_node: properties
+                .get("FirstNode")
+                .and_then(PropertyValue::as_object_index)
+                .unwrap_or_default(),
+            duration: properties.get("Duration").and_then(PropertyValue::as_f64),
+            volume_multiplier: properties
+                .get("VolumeMultiplier")
+                .and_then(PropertyValue::as_f64)
+                .unwrap_or(1.0),
+            pitch_multiplier: properties
+                .get("PitchMultiplier")
+                .and_then(PropertyValue::as_f64)
+                .unwrap_or(1.0),
+            nodes: Vec::new(),
+        };
+
+        let cue_index = export_index as i32 + 1;
+        let n_exports = self.get_exports()?.len();
+        for node_export in 0..n_exports {
+            let outer_index = self.get_exports()?[node_export].outer_index;
+            let class_name = self.get_export_class_name(node_export)?;
+            if outer_index != cue_index || !class_name.starts_with("SoundNode") {
+                continue;
+            }
+
+            let index = node_export as i32 + 1;
+            let node = self.read_export_properties(node_export)?;
+            graph.nodes.push(SoundCueNode {
+                index,
+                class_name,
+                name: self.get_object_name(index)?,
+                children: node
+                    .get("ChildNodes")
+                    .and_then(PropertyValue::as_array)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|child| child.as_object_index().unwrap_or_default())
+                    .collect(),
+                sound_wave: node
+                    .get("SoundWaveAssetPtr")
+                    .or_else(|| node.get("SoundWave"))
+                    .and_then(PropertyValue::as_object_path),
+            });
+        }
+
+        Ok(graph)
+    }
+}
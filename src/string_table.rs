@@ -0,0 +1,118 @@
+use std::io::{Read, Seek};
+
+use crate::errors::Result;
+use crate::parser::UassetParser;
+use crate::reader::UassetReader;
+
+#[derive(Debug, Clone, Default)]
+pub struct StringTableEntry {
+    pub key: String,
+    pub source_string: String,
+    /// Per-key metadata such as `Comment`, in serialized order.
+    pub metadata: Vec<(String, String)>,
+}
+
+impl StringTableEntry {
+    pub fn metadata_value(&self, name: &str) -> Option<&str> {
+        self.metadata
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StringTable {
+    pub namespace: String,
+    pub entries: Vec<StringTableEntry>,
+}
+
+impl StringTable {
+    pub fn find(&self, key: &str) -> Option<&StringTableEntry> {
+        self.entries.iter().find(|entry| entry.key == key)
+    }
+
+    /// Renders the table in the CSV format the editor imports: `Key` and
+    /// `SourceString` columns followed by one column per metadata name.
+    pub fn to_csv(&self) -> String {
+        let mut metadata_columns: Vec<&str> = Vec::new();
+        for entry in &self.entries {
+            for (name, _) in &entry.metadata {
+                if !metadata_columns.contains(&name.as_str()) {
+                    metadata_columns.push(name);
+                }
+            }
+        }
+
+        let mut csv = String::from("Key,SourceString");
+        for column in &metadata_columns {
+            csv.push(',');
+            csv.push_str(column);
+        }
+        csv.push('\n');
+
+        for entry in &self.entries {
+            csv.push_str(&csv_field(&entry.key));
+            csv.push(',');
+            csv.push_str(&csv_field(&entry.source_string));
+            for column in &metadata_columns {
+                csv.push(',');
+                csv.push_str(&csv_field(entry.metadata_value(column).unwrap_or_default()));
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+/// Quotes a CSV field, escaping quotes and line breaks the way the editor does.
+fn csv_field(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('"', "\"\"");
+    format!("\"{}\"", escaped)
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads a `UStringTable` export (0-based index into the export table): the
+    /// namespace, key to source string map and per-key metadata serialized
+    /// after the tagged properties.
+    pub fn read_string_table(&mut self, export_index: usize) -> Result<StringTable> {
+        self.seek_export_properties(export_index)?;
+
+        let namespace = self.reader.read_fstring()?;
+
+        let n_entries = self.read_element_count()?;
+
+        let mut entries = Vec::with_capacity(n_entries);
+        for _ in 0..n_entries {
+            entries.push(StringTableEntry {
+                key: self.reader.read_fstring()?,
+                source_string: self.reader.read_fstring()?,
+                metadata: Vec::new(),
+            });
+        }
+
+        let n_metadata = self.read_element_count()?;
+
+        for _ in 0..n_metadata {
+            let key = self.reader.read_fstring()?;
+
+            let n_values = self.read_element_count()?;
+
+            let mut metadata = Vec::with_capacity(n_values);
+            for _ in 0..n_values {
+                let name = self.reader.read_fname()?;
+                metadata.push((self.get_name_string(&name)?, self.reader.read_fstring()?));
+            }
+
+            if let Some(entry) = entries.iter_mut().find(|entry| entry.key == key) {
+                entry.metadata = metadata;
+            }
+        }
+
+        Ok(StringTable { namespace, entries })
+    }
+}
@@ -51,4 +51,11 @@ pub struct UassetSummary {
     pub additional_packages_to_cook: Vec<String>,
     pub asset_registry_data_offset: i32,
     pub bulk_data_start_offset: i64,
+    pub world_tile_info_data_offset: i32,
+    pub chunk_ids: Vec<i32>,
+    pub preload_dependency_count: i32,
+    pub preload_dependency_offset: i32,
+    pub names_referenced_from_export_data_count: Option<i32>,
+    pub payload_toc_offset: Option<i64>,
+    pub data_resource_offset: Option<i32>,
 }
@@ -0,0 +1,496 @@
+//! Classification of asset registry tag values, which are stored as exported
+//! property text: export paths, `NSLOCTEXT(...)` texts, numbers, booleans and
+//! struct text such as gameplay tag containers.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextValue {
+    /// `NSLOCTEXT("Namespace", "Key", "Source")` or `LOCTEXT("Key", "Source")`.
+    Localized {
+        namespace: String,
+        key: String,
+        source: String,
+    },
+    /// `INVTEXT("Source")`, culture invariant text.
+    Invariant(String),
+    /// `LOCTABLE("TableId", "Key")`, a string table reference.
+    StringTableEntry { table_id: String, key: String },
+    /// Any other text macro (`LOCGEN_NUMBER(...)`, ...), kept verbatim.
+    Other(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StructText {
+    Scalar(String),
+    Group(Vec<StructTextField>),
+}
+
+/// A field of struct text, unnamed for array elements.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructTextField {
+    pub name: Option<String>,
+    pub value: StructText,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TagValue {
+    /// An object reference, with the class when written as `Class'Path'`.
+    ObjectPath {
+        class_path: Option<String>,
+        path: String,
+    },
+    Text(TextValue),
+    Integer(i64),
+    Number(f64),
+    Bool(bool),
+    GameplayTags(Vec<String>),
+    Struct(Vec<StructTextField>),
+    String(String),
+}
+
+impl TagValue {
+    pub fn parse(value: &str) -> TagValue {
+        let trimmed = value.trim();
+
+        if let Some(text) = parse_text(trimmed) {
+            return TagValue::Text(text);
+        }
+
+        if let Some((class_path, path)) = parse_export_path(trimmed) {
+            return TagValue::ObjectPath {
+                class_path: Some(class_path),
+                path,
+            };
+        }
+
+        if is_object_path(trimmed) {
+            return TagValue::ObjectPath {
+                class_path: None,
+                path: trimmed.to_string(),
+            };
+        }
+
+        if trimmed.eq_ignore_ascii_case("true") {
+            return TagValue::Bool(true);
+        }
+        if trimmed.eq_ignore_ascii_case("false") {
+            return TagValue::Bool(false);
+        }
+
+        if let Ok(integer) = trimmed.parse::<i64>() {
+            return TagValue::Integer(integer);
+        }
+        if let Ok(number) = trimmed.parse::<f64>()
+            && number.is_finite()
+        {
+            return TagValue::Number(number);
+        }
+
+        if trimmed.starts_with('(')
+            && trimmed.ends_with(')')
+            && let Some(StructText::Group(fields)) = StructTextParser::new(trimmed).parse()
+        {
+            if let Some(tags) = gameplay_tags(&fields) {
+                return TagValue::GameplayTags(tags);
+            }
+            return TagValue::Struct(fields);
+        }
+
+        TagValue::String(value.to_string())
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            TagValue::Bool(value) => Some(*value),
+            TagValue::Integer(value) => Some(*value != 0),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            TagValue::Integer(value) => Some(*value as f64),
+            TagValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_object_path(&self) -> Option<&str> {
+        match self {
+            TagValue::ObjectPath { path, .. } => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Display string of a text value, i.e. its source string.
+    pub fn as_text_source(&self) -> Option<&str> {
+        match self {
+            TagValue::Text(TextValue::Localized { source, .. })
+            | TagValue::Text(TextValue::Invariant(source)) => Some(source),
+            TagValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl StructText {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            StructText::Scalar(value) => Some(value),
+            StructText::Group(_) => None,
+        }
+    }
+
+    pub fn field(&self, name: &str) -> Option<&StructText> {
+        match self {
+            StructText::Group(fields) => fields
+                .iter()
+                .find(|field| field.name.as_deref() == Some(name))
+                .map(|field| &field.value),
+            StructText::Scalar(_) => None,
+        }
+    }
+}
+
+/// Parses the arguments of a text macro such as `NSLOCTEXT("A", "B", "C")`.
+fn parse_text(value: &str) -> Option<TextValue> {
+    let open = value.find('(')?;
+    if !value.ends_with(')') {
+        return None;
+    }
+
+    let macro_name = &value[..open];
+    if macro_name.is_empty()
+        || !macro_name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c == '_')
+    {
+        return None;
+    }
+
+    let args = parse_quoted_args(&value[open + 1..value.len() - 1]);
+
+    match (macro_name, args.as_deref()) {
+        ("NSLOCTEXT", Some([namespace, key, source])) => Some(TextValue::Localized {
+            namespace: namespace.clone(),
+            key: key.clone(),
+            source: source.clone(),
+        }),
+        ("LOCTEXT", Some([key, source])) => Some(TextValue::Localized {
+            namespace: String::new(),
+            key: key.clone(),
+            source: source.clone(),
+        }),
+        ("INVTEXT", Some([source])) => Some(TextValue::Invariant(source.clone())),
+        ("LOCTABLE", Some([table_id, key])) => Some(TextValue::StringTableEntry {
+            table_id: table_id.clone(),
+            key: key.clone(),
+        }),
+        _ if macro_name.starts_with("LOCGEN_") => Some(TextValue::Other(value.to_string())),
+        _ => None,
+    }
+}
+
+/// Splits `"A", "B"` into unescaped strings. Returns `None` if any argument
+/// is not a quoted string.
+fn parse_quoted_args(args: &str) -> Option<Vec<String>> {
+    let mut result = Vec::new();
+    let mut chars = args.chars().peekable();
+
+    loop {
+        while chars.peek().is_some_and(|c| c.is_whitespace()) {
+            chars.next();
+        }
+        if chars.peek().is_none() {
+            break;
+        }
+
+        if chars.next()? != '"' {
+            return None;
+        }
+        result.push(read_quoted(&mut chars)?);
+
+        while chars.peek().is_some_and(|c| c.is_whitespace()) {
+            chars.next();
+        }
+        match chars.next() {
+            Some(',') => continue,
+            None => break,
+            Some(_) => return None,
+        }
+    }
+
+    Some(result)
+}
+
+/// Reads the rest of a quoted string after the opening quote.
+fn read_quoted(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> Option<String> {
+    let mut value = String::new();
+    loop {
+        match chars.next()? {
+            '\\' => match chars.next()? {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                other => value.push(other),
+            },
+            '"' => return Some(value),
+            other => value.push(other),
+        }
+    }
+}
+
+/// Parses `Class'Path'` and `/Script/Engine.Class'Path'` export paths.
+fn parse_export_path(value: &str) -> Option<(String, String)> {
+    let value = value.strip_suffix('\'')?;
+    let (class_path, path) = value.split_once('\'')?;
+    let path = path.trim_matches('"');
+
+    if class_path.is_empty()
+        || class_path.contains(char::is_whitespace)
+        || path.is_empty()
+        || path.contains('\'')
+    {
+        return None;
+    }
+
+    Some((class_path.to_string(), path.to_string()))
+}
+
+fn is_object_path(value: &str) -> bool {
+    value.starts_with('/')
+        && value.len() > 1
+        && !value.contains(char::is_whitespace)
+        && value[1..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphanumeric())
+}
+
+fn gameplay_tags(fields: &[StructTextField]) -> Option<Vec<String>> {
+    let field = fields
+        .iter()
+        .find(|field| field.name.as_deref() == Some("GameplayTags"))?;
+
+    let StructText::Group(entries) = &field.value else {
+        return Some(Vec::new());
+    };
+
+    Some(
+        entries
+            .iter()
+            .filter_map(|entry| match &entry.value {
+                StructText::Group(_) => entry.value.field("TagName")?.as_str(),
+                StructText::Scalar(value) => Some(value.as_str()),
+            })
+            .map(|tag| tag.to_string())
+            .collect(),
+    )
+}
+
+/// Parser for exported struct text like `(A=1,B=(C="x"),D=(1,2))`.
+struct StructTextParser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> StructTextParser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            chars: text.chars().peekable(),
+        }
+    }
+
+    fn parse(mut self) -> Option<StructText> {
+        let value = self.parse_value()?;
+        self.skip_whitespace();
+        if self.chars.peek().is_some() {
+            return None;
+        }
+        Some(value)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+
+    fn parse_value(&mut self) -> Option<StructText> {
+        self.skip_whitespace();
+        match self.chars.peek()? {
+            '(' => {
+                self.chars.next();
+                self.parse_group()
+            }
+            '"' => {
+                self.chars.next();
+                Some(StructText::Scalar(read_quoted(&mut self.chars)?))
+            }
+            _ => Some(StructText::Scalar(self.read_token())),
+        }
+    }
+
+    fn parse_group(&mut self) -> Option<StructText> {
+        let mut fields = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.chars.peek() == Some(&')') {
+                self.chars.next();
+                return Some(StructText::Group(fields));
+            }
+
+            let value = self.parse_value()?;
+            self.skip_whitespace();
+
+            let field = if self.chars.peek() == Some(&'=') {
+                self.chars.next();
+                let StructText::Scalar(name) = value else {
+                    return None;
+                };
+                StructTextField {
+                    name: Some(name),
+                    value: self.parse_value()?,
+                }
+            } else {
+                StructTextField { name: None, value }
+            };
+            fields.push(field);
+
+            self.skip_whitespace();
+            match self.chars.next()? {
+                ',' => continue,
+                ')' => return Some(StructText::Group(fields)),
+                _ => return None,
+            }
+        }
+    }
+
+    /// Reads an unquoted token, keeping quoted parts and nested parentheses
+    /// of values such as `Class'Path'` or `NSLOCTEXT(...)` intact.
+    fn read_token(&mut self) -> String {
+        let mut token = String::new();
+        let mut depth = 0;
+        let mut in_quote = None;
+
+        while let Some(&c) = self.chars.peek() {
+            match in_quote {
+                Some(quote) => {
+                    if c == quote {
+                        in_quote = None;
+                    }
+                }
+                None => match c {
+                    '\'' | '"' => in_quote = Some(c),
+                    '(' => depth += 1,
+                    ')' if depth == 0 => break,
+                    ')' => depth -= 1,
+                    ',' | '=' if depth == 0 => break,
+                    _ => {}
+                },
+            }
+            token.push(c);
+            self.chars.next();
+        }
+
+        token.trim().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_text_macros() {
+        assert_eq!(
+            TagValue::parse(r#"NSLOCTEXT("Game", "Key", "Say \"hi\"")"#),
+            TagValue::Text(TextValue::Localized {
+                namespace: "Game".to_string(),
+                key: "Key".to_string(),
+                source: "Say \"hi\"".to_string(),
+            })
+        );
+        assert_eq!(
+            TagValue::parse(r#"LOCTABLE("/Game/Strings.Strings", "Title")"#),
+            TagValue::Text(TextValue::StringTableEntry {
+                table_id: "/Game/Strings.Strings".to_string(),
+                key: "Title".to_string(),
+            })
+        );
+        assert_eq!(
+            TagValue::parse(r#"INVTEXT("Line\nBreak")"#).as_text_source(),
+            Some("Line\nBreak")
+        );
+        assert!(matches!(
+            TagValue::parse("LOCGEN_NUMBER(1, \"\")"),
+            TagValue::Text(TextValue::Other(_))
+        ));
+    }
+
+    #[test]
+    fn parses_object_paths() {
+        assert_eq!(
+            TagValue::parse("/Script/Engine.Texture2D'/Game/T_Rock.T_Rock'"),
+            TagValue::ObjectPath {
+                class_path: Some("/Script/Engine.Texture2D".to_string()),
+                path: "/Game/T_Rock.T_Rock".to_string(),
+            }
+        );
+        assert_eq!(
+            TagValue::parse("/Game/Maps/Main.Main").as_object_path(),
+            Some("/Game/Maps/Main.Main")
+        );
+        assert!(matches!(
+            TagValue::parse("/ not a path"),
+            TagValue::String(_)
+        ));
+    }
+
+    #[test]
+    fn parses_scalars() {
+        assert_eq!(TagValue::parse("True").as_bool(), Some(true));
+        assert_eq!(TagValue::parse("false").as_bool(), Some(false));
+        assert_eq!(TagValue::parse("-42"), TagValue::Integer(-42));
+        assert_eq!(TagValue::parse("1.5"), TagValue::Number(1.5));
+        assert_eq!(TagValue::parse("inf"), TagValue::String("inf".to_string()));
+        assert_eq!(
+            TagValue::parse(" padded "),
+            TagValue::String(" padded ".to_string())
+        );
+    }
+
+    #[test]
+    fn parses_gameplay_tag_containers() {
+        assert_eq!(
+            TagValue::parse(r#"(GameplayTags=((TagName="Item.Weapon"),(TagName="Item.Rare")))"#),
+            TagValue::GameplayTags(vec!["Item.Weapon".to_string(), "Item.Rare".to_string()])
+        );
+        assert_eq!(
+            TagValue::parse("(GameplayTags=)"),
+            TagValue::GameplayTags(Vec::new())
+        );
+    }
+
+    #[test]
+    fn parses_nested_struct_text() {
+        let TagValue::Struct(fields) = TagValue::parse(r#"(A=1,B=(C="x,y"),D=(2,3))"#) else {
+            panic!("expected struct text");
+        };
+        let text = StructText::Group(fields);
+
+        assert_eq!(text.field("A").and_then(StructText::as_str), Some("1"));
+        assert_eq!(
+            text.field("B")
+                .and_then(|b| b.field("C"))
+                .and_then(StructText::as_str),
+            Some("x,y")
+        );
+        let Some(StructText::Group(elements)) = text.field("D") else {
+            panic!("expected an array");
+        };
+        let elements: Vec<_> = elements
+            .iter()
+            .map(|element| (element.name.as_deref(), element.value.as_str()))
+            .collect();
+        assert_eq!(elements, [(None, Some("2")), (None, Some("3"))]);
+    }
+}
@@ -0,0 +1,364 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{Read, Seek};
+use std::path::Path;
+
+use crate::data_resource::{DataResource, EditorBulkData};
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::pixel_format::{decode_source_to_rgba, decode_to_rgba};
+use crate::png;
+use crate::property_value::PropertyValue;
+use crate::reader::UassetReader;
+
+const PKG_FILTER_EDITOR_ONLY: u32 = 0x8000_0000;
+
+// FStripDataFlags::EStrippedData
+const STRIP_EDITOR_DATA: u8 = 0x01;
+
+// FTexturePlatformData::PackedData
+const PLATFORM_DATA_HAS_OPT_DATA: u32 = 1 << 30;
+const PLATFORM_DATA_HAS_CPU_COPY: u32 = 1 << 29;
+const PLATFORM_DATA_NUM_SLICES_MASK: u32 = (1 << 29) - 1;
+
+/// Source art of a texture as imported into the editor (`FTextureSource`).
+#[derive(Debug, Clone, Default)]
+pub struct TextureSource {
+    pub size_x: i32,
+    pub size_y: i32,
+    pub num_slices: i32,
+    pub num_mips: i32,
+    /// `ETextureSourceFormat` name, e.g. `TSF_BGRA8`.
+    pub format: String,
+    /// `ETextureSourceCompressionFormat` name, e.g. `TSCF_PNG`.
+    pub compression_format: String,
+    /// Payload of the source mips, if the editor data was not stripped.
+    pub payload: Option<SourcePayload>,
+}
+
+#[derive(Debug, Clone)]
+pub enum SourcePayload {
+    /// UE4 packages store the source as regular bulk data.
+    BulkData(DataResource),
+    /// UE5 packages store it as editor bulk data, usually in the package trailer.
+    EditorBulkData(EditorBulkData),
+}
+
+impl TextureSource {
+    pub fn is_png_compressed(&self) -> bool {
+        self.compression_format == "TSCF_PNG"
+    }
+
+    pub fn is_jpeg_compressed(&self) -> bool {
+        self.compression_format == "TSCF_JPEG"
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TextureMip {
+    pub size_x: i32,
+    pub size_y: i32,
+    pub size_z: i32,
+    pub bulk_data: DataResource,
+}
+
+/// Cooked texture data for one pixel format (`FTexturePlatformData`).
+#[derive(Debug, Clone, Default)]
+pub struct TexturePlatformData {
+    /// `EPixelFormat` name, e.g. `PF_DXT5`.
+    pub pixel_format: String,
+    pub size_x: i32,
+    pub size_y: i32,
+    pub num_slices: u32,
+    pub first_mip_to_serialize: i32,
+    pub mips: Vec<TextureMip>,
+    pub is_virtual: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Texture2D {
+    /// `TextureGroup` name, e.g. `TEXTUREGROUP_World`.
+    pub lod_group: String,
+    /// `TextureCompressionSettings` name, e.g. `TC_Default`.
+    pub compression_settings: String,
+    pub srgb: bool,
+    pub source: Option<TextureSource>,
+    pub platform_data: Vec<TexturePlatformData>,
+}
+
+impl Texture2D {
+    /// Size of the cooked texture, or of the source art in editor packages.
+    pub fn size(&self) -> (i32, i32) {
+        if let Some(data) = self.platform_data.first() {
+            return (data.size_x, data.size_y);
+        }
+        self.source
+            .as_ref()
+            .map(|source| (source.size_x, source.size_y))
+            .unwrap_or_default()
+    }
+
+    pub fn mip_count(&self) -> usize {
+        if let Some(data) = self.platform_data.first() {
+            return data.mips.len();
+        }
+        self.source
+            .as_ref()
+            .map(|source| source.num_mips.max(0) as usize)
+            .unwrap_or_default()
+    }
+
+    pub fn pixel_format(&self) -> Option<&str> {
+        self.platform_data
+            .first()
+            .map(|data| data.pixel_format.as_str())
+    }
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads a `UTexture2D` export (0-based index into the export table):
+    /// texture settings and source info from its properties, the source
+    /// payload header and the cooked platform data.
+    pub fn read_texture_2d(&mut self, export_index: usize) -> Result<Texture2D> {
+        let properties = self.seek_export_properties(export_index)?;
+
+        let enum_name = |name: &str, default: &str| {
+            properties
+                .get(name)
+                .map(PropertyValue::export_text)
+                .unwrap_or_else(|| default.to_string())
+        };
+
+        let mut texture = Texture2D {
+            lod_group: enum_name("LODGroup", "TEXTUREGROUP_World"),
+            compression_settings: enum_name("CompressionSettings", "TC_Default"),
+            srgb: properties
+                .get("SRGB")
+                .and_then(PropertyValue::as_bool)
+                .unwrap_or(true),
+            source: properties.get("Source").map(texture_source),
+            platform_data: Vec::new(),
+        };
+
+        // UTexture
+        let strip_flags = self.reader.read_u8()?;
+        self.reader.read_u8()?; // class strip flags
+        if strip_flags & STRIP_EDITOR_DATA == 0 {
+            let payload = if self.summary.file_version_ue5 > 0 {
+                SourcePayload::EditorBulkData(self.read_editor_bulk_data_header()?)
+            } else {
+                SourcePayload::BulkData(self.read_bulk_data_header()?)
+            };
+            if let Some(source) = &mut texture.source {
+                source.payload = Some(payload);
+            }
+        }
+
+        // UTexture2D
+        self.reader.read_u8()?; // global strip flags
+        self.reader.read_u8()?; // class strip flags
+        let cooked = self.reader.read_u32::<LittleEndian>()? != 0;
+        if cooked {
+            texture.platform_data = self.read_cooked_platform_data()?;
+        }
+
+        Ok(texture)
+    }
+
+    fn read_cooked_platform_data(&mut self) -> Result<Vec<TexturePlatformData>> {
+        let mut platform_data = Vec::new();
+        loop {
+            let pixel_format = self.reader.read_fname()?;
+            if self.get_name_string(&pixel_format)? == "None" {
+                break;
+            }
+            self.reader.read_i64::<LittleEndian>()?; // SkipOffset
+            platform_data.push(self.read_texture_platform_data()?);
+        }
+        Ok(platform_data)
+    }
+
+    fn read_texture_platform_data(&mut self) -> Result<TexturePlatformData> {
+        const PLACEHOLDER_DERIVED_DATA_SIZE: i64 = 16;
+
+        let is_ue5 = self.summary.file_version_ue5 > 0;
+        let filter_editor_only = self.summary.package_flags & PKG_FILTER_EDITOR_ONLY != 0;
+        if is_ue5 && filter_editor_only {
+            self.reader.skip_bytes(PLACEHOLDER_DERIVED_DATA_SIZE)?;
+        }
+
+        let size_x = self.reader.read_i32::<LittleEndian>()?;
+        let size_y = self.reader.read_i32::<LittleEndian>()?;
+        let packed_data = self.reader.read_u32::<LittleEndian>()?;
+        let pixel_format = self.reader.read_fstring()?;
+
+        if packed_data & PLATFORM_DATA_HAS_OPT_DATA != 0 {
+            self.reader.read_u32::<LittleEndian>()?; // ExtData
+            self.reader.read_u32::<LittleEndian>()?; // NumMipsInTail
+        }
+        if packed_data & PLATFORM_DATA_HAS_CPU_COPY != 0 {
+            return Err(ParseError::UnsupportedPixelFormat(format!(
+                "{} (CPU copy)",
+                pixel_format
+            )));
+        }
+
+        let first_mip_to_serialize = self.reader.read_i32::<LittleEndian>()?;
+        let n_mips = self.read_element_count()?;
+
+        let mut mips = Vec::with_capacity(n_mips);
+        for _ in 0..n_mips {
+            if !is_ue5 {
+                self.reader.read_u32::<LittleEndian>()?; // bCooked
+            }
+            let bulk_data = self.read_bulk_data_header()?;
+            mips.push(TextureMip {
+                bulk_data,
+                size_x: self.reader.read_i32::<LittleEndian>()?,
+                size_y: self.reader.read_i32::<LittleEndian>()?,
+                size_z: self.reader.read_i32::<LittleEndian>()?,
+            });
+            if !filter_editor_only {
+                self.reader.read_fstring()?; // DerivedDataKey
+            }
+        }
+
+        let is_virtual = self.reader.read_u32::<LittleEndian>()? != 0;
+
+        Ok(TexturePlatformData {
+            pixel_format,
+            size_x,
+            size_y,
+            num_slices: packed_data & PLATFORM_DATA_NUM_SLICES_MASK,
+            first_mip_to_serialize,
+            mips,
+            is_virtual,
+        })
+    }
+
+    /// Decodes the largest available mip of a texture to 8-bit RGBA and
+    /// returns its size and pixels. Cooked mips are read from the package or
+    /// its `.ubulk`; editor packages fall back to the uncompressed source art.
+    pub fn read_texture_rgba(
+        &mut self,
+        texture: &Texture2D,
+        package_path: Option<&Path>,
+    ) -> Result<(u32, u32, Vec<u8>)> {
+        if let Some(data) = texture.platform_data.first() {
+            let mip = data
+                .mips
+                .iter()
+                .find(|mip| !mip.bulk_data.is_unused() && mip.bulk_data.serial_size > 0)
+                .ok_or_else(|| ParseError::UnsupportedPixelFormat(data.pixel_format.clone()))?;
+            if mip.bulk_data.is_compressed() {
+                return Err(ParseError::CompressedBulkDataNotSupported);
+            }
+
+            let pixels = self.read_bulk_data(&mip.bulk_data, package_path)?;
+            let (width, height) = texture_size(mip.size_x, mip.size_y)?;
+            let rgba = decode_to_rgba(&data.pixel_format, width, height, &pixels)
+                .ok_or_else(|| ParseError::UnsupportedPixelFormat(data.pixel_format.clone()))?;
+            return Ok((width, height, rgba));
+        }
+
+        let source = texture
+            .source
+            .as_ref()
+            .ok_or_else(|| ParseError::UnsupportedPixelFormat("None".to_string()))?;
+        let pixels = self.read_texture_source(source, package_path)?;
+        let (width, height) = texture_size(source.size_x, source.size_y)?;
+        let rgba = decode_source_to_rgba(&source.format, width, height, &pixels)
+            .ok_or_else(|| ParseError::UnsupportedPixelFormat(source.format.clone()))?;
+        Ok((width, height, rgba))
+    }
+
+    /// Reads the source payload of a texture as stored: raw mips, or a PNG or
+    /// JPEG file for compressed sources.
+    pub fn read_texture_source(
+        &mut self,
+        source: &TextureSource,
+        package_path: Option<&Path>,
+    ) -> Result<Vec<u8>> {
+        match &source.payload {
+            Some(SourcePayload::BulkData(resource)) => {
+                if resource.is_compressed() {
+                    return Err(ParseError::CompressedBulkDataNotSupported);
+                }
+                self.read_bulk_data(resource, package_path)
+            }
+            Some(SourcePayload::EditorBulkData(bulk_data)) => self.read_editor_bulk_data(bulk_data),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Encodes the largest mip of a texture as an image file. PNG or JPEG
+    /// compressed sources are returned as stored, with their file extension.
+    pub fn export_texture_image(
+        &mut self,
+        texture: &Texture2D,
+        package_path: Option<&Path>,
+    ) -> Result<(Vec<u8>, &'static str)> {
+        if texture.platform_data.is_empty()
+            && let Some(source) = &texture.source
+        {
+            if source.is_png_compressed() {
+                return Ok((self.read_texture_source(source, package_path)?, "png"));
+            }
+            if source.is_jpeg_compressed() {
+                return Ok((self.read_texture_source(source, package_path)?, "jpg"));
+            }
+        }
+
+        let (width, height, rgba) = self.read_texture_rgba(texture, package_path)?;
+        Ok((png::encode_rgba(width, height, &rgba), "png"))
+    }
+}
+
+fn texture_source(value: &PropertyValue) -> TextureSource {
+    let int = |name: &str| {
+        value
+            .field(name)
+            .and_then(PropertyValue::as_i64)
+            .unwrap_or_default() as i32
+    };
+    let enum_name = |name: &str| value.field(name).map(PropertyValue::export_text);
+
+    let format = enum_name("Format")
+        .or_else(|| {
+            value
+                .field("LayerFormat")
+                .and_then(PropertyValue::as_array)
+                .and_then(|formats| formats.first())
+                .map(PropertyValue::export_text)
+        })
+        .unwrap_or_default();
+
+    let png_compressed = value
+        .field("bPNGCompressed")
+        .and_then(PropertyValue::as_bool)
+        .unwrap_or_default();
+    let compression_format = enum_name("CompressionFormat").unwrap_or_else(|| {
+        if png_compressed {
+            "TSCF_PNG".to_string()
+        } else {
+            "TSCF_None".to_string()
+        }
+    });
+
+    TextureSource {
+        size_x: int("SizeX"),
+        size_y: int("SizeY"),
+        num_slices: int("NumSlices").max(1),
+        num_mips: int("NumMips"),
+        format,
+        compression_format,
+        payload: None,
+    }
+}
+
+/// Converts mip or source dimensions read from the package to pixel sizes.
+fn texture_size(size_x: i32, size_y: i32) -> Result<(u32, u32)> {
+    if size_x <= 0 || size_y <= 0 {
+        return Err(ParseError::InvalidTextureSize(size_x, size_y));
+    }
+    Ok((size_x as u32, size_y as u32))
+}
@@ -0,0 +1,52 @@
+use std::io;
+use std::path::Path;
+
+use crate::png;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThumbnailFormat {
+    Png,
+    Jpeg,
+    /// Uncompressed BGRA pixels written by old engine versions.
+    RawBgra,
+}
+
+#[derive(Debug)]
+pub struct ObjectThumbnail {
+    pub width: i32,
+    pub height: i32,
+    pub format: ThumbnailFormat,
+    /// Image data as stored in the package.
+    pub data: Vec<u8>,
+}
+
+impl ObjectThumbnail {
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0 || self.data.is_empty()
+    }
+
+    /// Returns an encoded image file, converting raw BGRA data to PNG.
+    pub fn image_bytes(&self) -> Vec<u8> {
+        match self.format {
+            ThumbnailFormat::Png | ThumbnailFormat::Jpeg => self.data.clone(),
+            ThumbnailFormat::RawBgra => {
+                png::encode_bgra(self.width as u32, self.height as u32, &self.data)
+            }
+        }
+    }
+
+    pub fn file_extension(&self) -> &'static str {
+        match self.format {
+            ThumbnailFormat::Jpeg => "jpg",
+            ThumbnailFormat::Png | ThumbnailFormat::RawBgra => "png",
+        }
+    }
+
+    /// Writes the image to `path`, replacing its extension with the one matching the format.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(
+            path.with_extension(self.file_extension()),
+            self.image_bytes(),
+        )
+    }
+}
@@ -1,5 +1,37 @@
-#[derive(Debug, Clone)]
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FName {
     pub index: i32,
     pub number: i32,
 }
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SoftObjectPath {
+    pub package_name: String,
+    pub asset_name: String,
+    pub sub_path: String,
+}
+
+impl SoftObjectPath {
+    pub fn is_null(&self) -> bool {
+        self.package_name.is_empty() || self.package_name == "None"
+    }
+}
+
+impl fmt::Display for SoftObjectPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_null() {
+            return Ok(());
+        }
+
+        write!(f, "{}", self.package_name)?;
+        if !self.asset_name.is_empty() && self.asset_name != "None" {
+            write!(f, ".{}", self.asset_name)?;
+        }
+        if !self.sub_path.is_empty() {
+            write!(f, ":{}", self.sub_path)?;
+        }
+        Ok(())
+    }
+}
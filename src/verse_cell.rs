@@ -0,0 +1,18 @@
+#[derive(Debug, Clone)]
+pub struct CellImport {
+    /// Import of the package containing the cell.
+    pub package_index: i32,
+    pub verse_path: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CellExport {
+    pub cpp_class_info: String,
+    pub verse_path: String,
+    pub serial_offset: i64,
+    pub serial_layout_size: i64,
+    pub serial_size: i64,
+    pub first_export_dependency: i32,
+    pub serialization_before_serialization_dependencies: i32,
+    pub create_before_serialization_dependencies: i32,
+}
@@ -0,0 +1,232 @@
+use byteorder::{LittleEndian, ReadBytesExt};
+use std::io::{Read, Seek};
+
+use crate::errors::{ParseError, Result};
+use crate::parser::UassetParser;
+use crate::property::ObjectProperties;
+use crate::property_value::{Property, PropertyValue};
+
+/// Relative transform of an actor's root component.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActorTransform {
+    pub location: [f64; 3],
+    /// Pitch, yaw and roll in degrees.
+    pub rotation: [f64; 3],
+    pub scale: [f64; 3],
+}
+
+impl Default for ActorTransform {
+    fn default() -> Self {
+        Self {
+            location: [0.0; 3],
+            rotation: [0.0; 3],
+            scale: [1.0; 3],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LevelActor {
+    /// Package index of the actor export.
+    pub index: i32,
+    pub class_name: String,
+    pub name: String,
+    /// Label shown in the editor's outliner, if it differs from the name.
+    pub label: Option<String>,
+    /// Outliner folder, e.g. `Lighting/Sky`.
+    pub folder_path: Option<String>,
+    /// `None` for actors without a root component.
+    pub transform: Option<ActorTransform>,
+}
+
+impl LevelActor {
+    pub fn display_name(&self) -> &str {
+        self.label.as_deref().unwrap_or(&self.name)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StreamingLevel {
+    /// `LevelStreamingDynamic`, `LevelStreamingAlwaysLoaded`, ...
+    pub class_name: String,
+    /// Path of the streamed world asset.
+    pub world_asset: String,
+    pub initially_loaded: bool,
+    pub initially_visible: bool,
+}
+
+/// The persistent level of a `UWorld` package.
+#[derive(Debug, Clone, Default)]
+pub struct WorldInventory {
+    pub world: String,
+    pub actors: Vec<LevelActor>,
+    pub streaming_levels: Vec<StreamingLevel>,
+    /// Class of the world settings actor, e.g. `WorldSettings`.
+    pub world_settings_class: Option<String>,
+    pub world_settings: Vec<Property>,
+}
+
+impl WorldInventory {
+    pub fn actors_of_class<'a>(
+        &'a self,
+        class_name: &'a str,
+    ) -> impl Iterator<Item = &'a LevelActor> {
+        self.actors
+            .iter()
+            .filter(move |actor| actor.class_name == class_name)
+    }
+}
+
+impl<R: Read + Seek> UassetParser<R> {
+    /// Reads the actors, streaming levels and world settings of the package's
+    /// `World` export.
+    pub fn read_world(&mut self) -> Result<WorldInventory> {
+        let world_index = *self
+            .find_exports_by_class("World")?
+            .first()
+            .ok_or_else(|| ParseError::UnexpectedExportClass("World".to_string()))?;
+
+        let world = self.read_export_properties(world_index)?;
+        let mut inventory = WorldInventory {
+            world: self.get_object_path(world_index as i32 + 1)?,
+            ..Default::default()
+        };
+
+        if let Some(level_index) = world
+            .get("PersistentLevel")
+            .and_then(PropertyValue::as_object_index)
+            .filter(|index| *index > 0)
+        {
+            let level_export = level_index as usize - 1;
+            inventory.actors = self.read_level_actors(level_export)?;
+
+            let level = self.read_export_properties(level_export)?;
+            if let Some(settings_index) = level
+                .get("WorldSettings")
+                .and_then(PropertyValue::as_object_index)
+                .filter(|index| *index > 0)
+            {
+                let settings_export = settings_index as usize - 1;
+                inventory.world_settings_class = Some(self.get_export_class_name(settings_export)?);
+                inventory.world_settings = self.read_export_properties(settings_export)?.properties;
+            }
+        }
+
+        let streaming_indices: Vec<i32> = world
+            .get("StreamingLevels")
+            .and_then(PropertyValue::as_array)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(PropertyValue::as_object_index)
+            .filter(|index| *index > 0)
+            .collect();
+        for index in streaming_indices {
+            let export_index = index as usize - 1;
+            let streaming = self.read_export_properties(export_index)?;
+            let flag = |name: &str| {
+                streaming
+                    .get(name)
+                    .and_then(PropertyValue::as_bool)
+                    .unwrap_or_default()
+            };
+            inventory.streaming_levels.push(StreamingLevel {
+                class_name: self.get_export_class_name(export_index)?,
+                world_asset: streaming
+                    .get("WorldAsset")
+                    .and_then(PropertyValue::as_object_path)
+                    .unwrap_or_default(),
+                initially_loaded: flag("bInitiallyLoaded"),
+                initially_visible: flag("bInitiallyVisible"),
+            });
+        }
+
+        Ok(inventory)
+    }
+
+    /// Reads the actors of a `Level` export (0-based index into the export
+    /// table) from the actor list serialized after its properties. Actors
+    /// stored in other packages are skipped.
+    pub fn read_level_actors(&mut self, level_index: usize) -> Result<Vec<LevelActor>> {
+        let class_name = self.get_export_class_name(level_index)?;
+        if class_name != "Level" {
+            return Err(ParseError::UnexpectedExportClass(class_name));
+        }
+
+        self.seek_export_properties(level_index)?;
+        let n_actors = self.read_element_count()?;
+        let mut actor_indices = Vec::with_capacity(n_actors);
+        for _ in 0..n_actors {
+            actor_indices.push(self.reader.read_i32::<LittleEndian>()?);
+        }
+
+        let mut actors = Vec::with_capacity(actor_indices.len());
+        for index in actor_indices.into_iter().filter(|index| *index > 0) {
+            actors.push(self.read_actor(index as usize - 1)?);
+        }
+        Ok(actors)
+    }
+
+    /// Reads the class, name, label, folder and root component transform of
+    /// an actor export (0-based index into the export table).
+    pub fn read_actor(&mut self, export_index: usize) -> Result<LevelActor> {
+        let properties = self.read_export_properties(export_index)?;
+        self.read_actor_with_properties(export_index, &properties)
+    }
+
+    /// Same as [`Self::read_actor`], for an actor whose properties have
+    /// already been read.
+    pub(crate) fn read_actor_with_properties(
+        &mut self,
+        export_index: usize,
+        properties: &ObjectProperties,
+    ) -> Result<LevelActor> {
+        let index = export_index as i32 + 1;
+
+        let transform = match properties
+            .get("RootComponent")
+            .and_then(PropertyValue::as_object_index)
+            .filter(|index| *index > 0)
+        {
+            Some(component) => {
+                let component = self.read_export_properties(component as usize - 1)?;
+                let vector = |name: &str, default: f64| {
+                    let value = component.get(name);
+                    ["X", "Y", "Z"].map(|axis| {
+                        value
+                            .and_then(|v| v.field(axis))
+                            .and_then(PropertyValue::as_f64)
+                            .unwrap_or(default)
+                    })
+                };
+                let rotation = component.get("RelativeRotation");
+                Some(ActorTransform {
+                    location: vector("RelativeLocation", 0.0),
+                    rotation: ["Pitch", "Yaw", "Roll"].map(|axis| {
+                        rotation
+                            .and_then(|v| v.field(axis))
+                            .and_then(PropertyValue::as_f64)
+                            .unwrap_or_default()
+                    }),
+                    scale: vector("RelativeScale3D", 1.0),
+                })
+            }
+            None => None,
+        };
+
+        Ok(LevelActor {
+            index,
+            class_name: self.get_export_class_name(export_index)?,
+            name: self.get_object_name(index)?,
+            label: properties
+                .get("ActorLabel")
+                .and_then(PropertyValue::as_str)
+                .map(str::to_string),
+            folder_path: properties
+                .get("FolderPath")
+                .and_then(PropertyValue::as_str)
+                .filter(|path| !path.is_empty() && *path != "None")
+                .map(str::to_string),
+            transform,
+        })
+    }
+}